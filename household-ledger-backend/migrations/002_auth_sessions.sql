@@ -0,0 +1,15 @@
+-- 로그인 세션 (refresh token 회전 및 재사용 감지)
+-- 세션 하나가 하나의 refresh token family이며, 현재 유효한 refresh token의 jti만 보관합니다.
+
+CREATE TABLE IF NOT EXISTS auth_sessions (
+    id CHAR(36) PRIMARY KEY,
+    user_id BIGINT NOT NULL,
+    refresh_jti CHAR(36) NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_used_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at DATETIME NOT NULL,
+    revoked_at DATETIME,
+    INDEX idx_auth_sessions_user (user_id, revoked_at),
+    INDEX idx_auth_sessions_expires (expires_at),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,5 @@
+-- 세션별 기기 정보 (세션 목록 및 원격 로그아웃용)
+
+ALTER TABLE auth_sessions
+    ADD COLUMN user_agent VARCHAR(255) AFTER refresh_jti,
+    ADD COLUMN ip_address VARCHAR(45) AFTER user_agent;
@@ -0,0 +1,15 @@
+-- 사용자 일회용 토큰 (비밀번호 재설정 등)
+-- 원문 토큰은 저장하지 않고 SHA-256 해시만 보관합니다.
+
+CREATE TABLE IF NOT EXISTS user_tokens (
+    id BIGINT PRIMARY KEY AUTO_INCREMENT,
+    user_id BIGINT NOT NULL,
+    purpose ENUM('PASSWORD_RESET') NOT NULL,
+    token_hash CHAR(64) UNIQUE NOT NULL,
+    expires_at DATETIME NOT NULL,
+    used_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_user_tokens_user_purpose (user_id, purpose),
+    INDEX idx_user_tokens_expires (expires_at),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
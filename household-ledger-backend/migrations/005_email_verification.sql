@@ -0,0 +1,10 @@
+-- 이메일 인증 상태
+-- 기존 사용자는 가입 시점에 인증된 것으로 간주합니다.
+
+ALTER TABLE users
+    ADD COLUMN email_verified_at DATETIME NULL AFTER password_hash;
+
+UPDATE users SET email_verified_at = created_at WHERE email_verified_at IS NULL;
+
+ALTER TABLE user_tokens
+    MODIFY COLUMN purpose ENUM('PASSWORD_RESET', 'EMAIL_VERIFICATION') NOT NULL;
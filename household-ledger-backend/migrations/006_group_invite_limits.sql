@@ -0,0 +1,6 @@
+-- 초대 코드 사용 횟수 제한
+-- max_uses가 NULL이면 만료 전까지 무제한으로 사용할 수 있습니다.
+
+ALTER TABLE group_invites
+    ADD COLUMN max_uses INT NULL AFTER created_by,
+    ADD COLUMN use_count INT NOT NULL DEFAULT 0 AFTER max_uses;
@@ -0,0 +1,19 @@
+-- 그룹 멤버십 (한 사용자가 여러 그룹에 속할 수 있음)
+-- users.group_id는 요청에 그룹을 지정하지 않았을 때 사용할 기본 그룹으로 유지합니다.
+
+CREATE TABLE IF NOT EXISTS group_members (
+    group_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    joined_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (group_id, user_id),
+    INDEX idx_group_members_user (user_id),
+    FOREIGN KEY (group_id) REFERENCES groups(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- 기존 단일 그룹 소속과 그룹 소유자를 멤버십으로 옮김
+INSERT IGNORE INTO group_members (group_id, user_id)
+SELECT group_id, id FROM users WHERE group_id IS NOT NULL;
+
+INSERT IGNORE INTO group_members (group_id, user_id, joined_at)
+SELECT id, owner_id, created_at FROM groups;
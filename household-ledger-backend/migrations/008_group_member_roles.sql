@@ -0,0 +1,9 @@
+-- 그룹 멤버 역할
+-- 새로 참여한 멤버는 EDITOR, 그룹 소유자는 OWNER 역할을 가집니다.
+
+ALTER TABLE group_members
+    ADD COLUMN role ENUM('OWNER', 'ADMIN', 'EDITOR', 'VIEWER') NOT NULL DEFAULT 'EDITOR' AFTER user_id;
+
+UPDATE group_members gm
+INNER JOIN groups g ON g.id = gm.group_id AND g.owner_id = gm.user_id
+SET gm.role = 'OWNER';
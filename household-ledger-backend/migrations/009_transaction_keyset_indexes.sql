@@ -0,0 +1,9 @@
+-- 거래 목록 커서 페이지네이션 (date DESC, created_at DESC, id DESC)
+-- 정렬 키 전체를 포함하는 인덱스로 교체 (기존 인덱스는 새 인덱스의 앞부분과 같음)
+ALTER TABLE transactions
+    ADD INDEX idx_tx_group_keyset (group_id, date, created_at, id),
+    ADD INDEX idx_tx_owner_keyset (owner_user_id, date, created_at, id);
+
+ALTER TABLE transactions
+    DROP INDEX idx_tx_group_date,
+    DROP INDEX idx_tx_owner_date;
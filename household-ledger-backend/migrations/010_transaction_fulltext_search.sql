@@ -0,0 +1,16 @@
+-- 거래 전문 검색 (가맹점, 메모, 영수증 OCR 텍스트)
+-- 한국어는 띄어쓰기 단위로 찾기 어려워 ngram 파서(기본 2글자 단위)를 사용합니다.
+
+-- OCR 결과에서 추출한 원문 텍스트 (검색용)
+ALTER TABLE receipts
+    ADD COLUMN ocr_text TEXT NULL AFTER ocr_result;
+
+UPDATE receipts
+SET ocr_text = COALESCE(JSON_UNQUOTE(JSON_EXTRACT(ocr_result, '$.text')), extracted_merchant)
+WHERE ocr_result IS NOT NULL OR extracted_merchant IS NOT NULL;
+
+ALTER TABLE transactions
+    ADD FULLTEXT INDEX ft_tx_merchant_memo (merchant, memo) WITH PARSER ngram;
+
+ALTER TABLE receipts
+    ADD FULLTEXT INDEX ft_receipts_ocr_text (ocr_text) WITH PARSER ngram;
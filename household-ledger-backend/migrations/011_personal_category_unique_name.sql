@@ -0,0 +1,19 @@
+-- 개인 카테고리 이름 중복 방지
+-- ux_category_name (group_id, name, type)은 group_id가 NULL인 개인 카테고리에는 적용되지 않으므로
+-- 개인 카테고리에만 값이 있는 생성 컬럼으로 같은 사용자 안에서의 중복을 막습니다.
+
+-- 기존 중복은 가장 먼저 만든 카테고리만 남기고 이름 뒤에 ID를 붙여 구분
+UPDATE categories c
+INNER JOIN (
+    SELECT created_by, name, type, MIN(id) AS keep_id
+    FROM categories
+    WHERE group_id IS NULL
+    GROUP BY created_by, name, type
+    HAVING COUNT(*) > 1
+) d ON d.created_by = c.created_by AND d.name = c.name AND d.type = c.type
+SET c.name = CONCAT(LEFT(c.name, 100), ' (', c.id, ')')
+WHERE c.group_id IS NULL AND c.id <> d.keep_id;
+
+ALTER TABLE categories
+    ADD COLUMN personal_owner_id BIGINT GENERATED ALWAYS AS (IF(group_id IS NULL, created_by, NULL)) STORED,
+    ADD UNIQUE KEY ux_category_personal_name (personal_owner_id, name, type);
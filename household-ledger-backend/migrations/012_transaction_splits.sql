@@ -0,0 +1,19 @@
+-- 거래 분할 (영수증 한 장을 여러 카테고리로 나눔)
+-- 분할 항목이 있는 거래는 통계와 예산 계산에서 부모 거래 대신 분할 항목으로 집계합니다.
+-- 분할 금액의 합은 부모 거래 금액과 같아야 합니다 (애플리케이션에서 검증).
+
+CREATE TABLE IF NOT EXISTS transaction_splits (
+    id BIGINT PRIMARY KEY AUTO_INCREMENT,
+    transaction_id BIGINT NOT NULL,
+    category_id BIGINT,
+    amount BIGINT NOT NULL,
+    memo VARCHAR(1000),
+    position INT NOT NULL DEFAULT 0,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    INDEX idx_tx_splits_transaction (transaction_id, position),
+    INDEX idx_tx_splits_category (category_id),
+    FOREIGN KEY (transaction_id) REFERENCES transactions(id) ON DELETE CASCADE,
+    FOREIGN KEY (category_id) REFERENCES categories(id) ON DELETE SET NULL,
+    CHECK (amount > 0)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
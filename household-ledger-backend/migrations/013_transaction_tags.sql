@@ -0,0 +1,64 @@
+-- 거래 태그를 다대다로 변경 (거래 하나에 여러 태그)
+-- 태그는 거래와 같은 범위(그룹 또는 개인)의 것만 붙일 수 있습니다.
+
+CREATE TABLE IF NOT EXISTS transaction_tags (
+    transaction_id BIGINT NOT NULL,
+    tag_id BIGINT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (transaction_id, tag_id),
+    INDEX idx_transaction_tags_tag (tag_id, transaction_id),
+    FOREIGN KEY (transaction_id) REFERENCES transactions(id) ON DELETE CASCADE,
+    FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- 기존 단일 태그를 옮긴 뒤 transactions.tag_id 제거
+INSERT IGNORE INTO transaction_tags (transaction_id, tag_id)
+SELECT id, tag_id FROM transactions WHERE tag_id IS NOT NULL;
+
+-- 외래키 이름이 자동 생성되어 있으므로 information_schema에서 찾아 삭제
+SET @tag_fk := (
+    SELECT CONSTRAINT_NAME
+    FROM information_schema.KEY_COLUMN_USAGE
+    WHERE TABLE_SCHEMA = DATABASE()
+      AND TABLE_NAME = 'transactions'
+      AND COLUMN_NAME = 'tag_id'
+      AND REFERENCED_TABLE_NAME = 'tags'
+    LIMIT 1
+);
+SET @drop_tag_fk := CONCAT('ALTER TABLE transactions DROP FOREIGN KEY ', @tag_fk);
+PREPARE stmt FROM @drop_tag_fk;
+EXECUTE stmt;
+DEALLOCATE PREPARE stmt;
+
+ALTER TABLE transactions
+    DROP INDEX idx_tx_tag,
+    DROP COLUMN tag_id;
+
+-- 개인 태그 이름 중복 방지 (ux_tag (group_id, name)은 group_id가 NULL이면 적용되지 않음)
+-- 기존 중복은 가장 먼저 만든 태그로 합친 뒤 삭제
+UPDATE IGNORE transaction_tags tt
+INNER JOIN tags t ON t.id = tt.tag_id
+INNER JOIN (
+    SELECT created_by, name, MIN(id) AS keep_id
+    FROM tags
+    WHERE group_id IS NULL
+    GROUP BY created_by, name
+    HAVING COUNT(*) > 1
+) d ON d.created_by = t.created_by AND d.name = t.name
+SET tt.tag_id = d.keep_id
+WHERE t.group_id IS NULL AND t.id <> d.keep_id;
+
+DELETE t
+FROM tags t
+INNER JOIN (
+    SELECT created_by, name, MIN(id) AS keep_id
+    FROM tags
+    WHERE group_id IS NULL
+    GROUP BY created_by, name
+    HAVING COUNT(*) > 1
+) d ON d.created_by = t.created_by AND d.name = t.name
+WHERE t.group_id IS NULL AND t.id <> d.keep_id;
+
+ALTER TABLE tags
+    ADD COLUMN personal_owner_id BIGINT GENERATED ALWAYS AS (IF(group_id IS NULL, created_by, NULL)) STORED,
+    ADD UNIQUE KEY ux_tag_personal_name (personal_owner_id, name);
@@ -0,0 +1,8 @@
+-- 상위/하위 카테고리 (예: 식비 > 외식, 장보기, 배달)
+-- 단계 수와 같은 범위/유형 여부는 애플리케이션에서 검증합니다.
+-- 그룹 삭제로 카테고리가 함께 삭제될 때 순서에 막히지 않도록 상위 카테고리 삭제 시 NULL로 둡니다.
+
+ALTER TABLE categories
+    ADD COLUMN parent_id BIGINT NULL AFTER created_by,
+    ADD INDEX idx_categories_parent (parent_id),
+    ADD CONSTRAINT fk_categories_parent FOREIGN KEY (parent_id) REFERENCES categories(id) ON DELETE SET NULL;
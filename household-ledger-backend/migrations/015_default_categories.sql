@@ -0,0 +1,19 @@
+-- 기본 카테고리 세트 (가입/그룹 생성 시 생성, 초기화와 새 버전 받기)
+-- default_key는 버전이 바뀌어도 유지되는 기본 카테고리 식별자로, 같은 범위에 한 번만 만들어지도록 고유 키를 둡니다.
+-- 그룹 카테고리는 (group_id, default_key), 개인 카테고리는 (personal_owner_id, default_key)로 중복을 막습니다.
+
+ALTER TABLE categories
+    ADD COLUMN default_key VARCHAR(40) NULL AFTER is_default,
+    ADD UNIQUE KEY ux_category_group_default (group_id, default_key),
+    ADD UNIQUE KEY ux_category_personal_default (personal_owner_id, default_key);
+
+-- 사용자(개인 카테고리) 또는 그룹별로 마지막으로 적용한 기본 카테고리 버전과 언어
+CREATE TABLE IF NOT EXISTS category_default_versions (
+    owner_type ENUM('USER', 'GROUP') NOT NULL,
+    owner_id BIGINT NOT NULL,
+    version INT UNSIGNED NOT NULL,
+    locale VARCHAR(5) NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    PRIMARY KEY (owner_type, owner_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,13 @@
+-- 영수증 OCR 검색 텍스트를 OCR 결과에서 항상 계산
+-- 010에서 만든 ocr_text는 마이그레이션 시점에 한 번만 채워져, 이후 저장한 OCR 결과가 검색되지 않았습니다.
+-- 생성 컬럼으로 바꿔 ocr_result나 extracted_merchant가 바뀌면 함께 갱신되도록 합니다.
+
+ALTER TABLE receipts
+    DROP INDEX ft_receipts_ocr_text;
+
+ALTER TABLE receipts
+    MODIFY COLUMN ocr_text TEXT
+        GENERATED ALWAYS AS (COALESCE(JSON_UNQUOTE(JSON_EXTRACT(ocr_result, '$.text')), extracted_merchant)) STORED;
+
+ALTER TABLE receipts
+    ADD FULLTEXT INDEX ft_receipts_ocr_text (ocr_text) WITH PARSER ngram;
@@ -0,0 +1,5 @@
+-- 거래 목록 금액순 커서 페이지네이션 (amount, id)
+-- 날짜순 인덱스(009)만으로는 금액순 정렬 시 범위 전체를 정렬해야 하므로 정렬 키를 포함하는 인덱스를 추가합니다.
+ALTER TABLE transactions
+    ADD INDEX idx_tx_group_amount_keyset (group_id, amount, id),
+    ADD INDEX idx_tx_owner_amount_keyset (owner_user_id, amount, id);
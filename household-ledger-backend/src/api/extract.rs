@@ -0,0 +1,115 @@
+use axum::{
+    async_trait,
+    body::Bytes,
+    extract::{
+        rejection::{JsonRejection, PathRejection, QueryRejection},
+        FromRequest, FromRequestParts, Request,
+    },
+    http::request::Parts,
+    response::{IntoResponse, Response},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use crate::errors::AppError;
+use crate::i18n::MessageKey;
+
+/// `axum::Json`과 같지만, 본문을 해석하지 못하면 다른 오류와 같은 형태(`code`, `request_id`)로 응답합니다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for Json<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let axum::Json(value) = axum::Json::<T>::from_request(request, state)
+            .await
+            .map_err(|rejection: JsonRejection| malformed(MessageKey::MalformedBody, rejection.body_text()))?;
+
+        Ok(Json(value))
+    }
+}
+
+impl<T: Serialize> IntoResponse for Json<T> {
+    fn into_response(self) -> Response {
+        axum::Json(self.0).into_response()
+    }
+}
+
+/// 본문이 비어 있으면 `None`, 있으면 [`Json`]과 같이 해석합니다.
+///
+/// `Option<Json<T>>`는 해석 실패도 `None`으로 바꾸므로, 잘못된 본문이 기본값으로 처리되지 않도록 이 타입을 사용합니다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OptionalJson<T>(pub Option<T>);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for OptionalJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let bytes = Bytes::from_request(request, state)
+            .await
+            .map_err(|rejection| malformed(MessageKey::MalformedBody, rejection.body_text()))?;
+        if bytes.iter().all(u8::is_ascii_whitespace) {
+            return Ok(OptionalJson(None));
+        }
+
+        let axum::Json(value) = axum::Json::<T>::from_bytes(&bytes)
+            .map_err(|rejection: JsonRejection| malformed(MessageKey::MalformedBody, rejection.body_text()))?;
+
+        Ok(OptionalJson(Some(value)))
+    }
+}
+
+/// `axum::extract::Query`와 같지만, 해석 실패를 `AppError`로 응답합니다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Query<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for Query<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let axum::extract::Query(value) = axum::extract::Query::<T>::from_request_parts(parts, state)
+            .await
+            .map_err(|rejection: QueryRejection| malformed(MessageKey::MalformedQuery, rejection.body_text()))?;
+
+        Ok(Query(value))
+    }
+}
+
+/// `axum::extract::Path`와 같지만, 해석 실패를 `AppError`로 응답합니다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Path<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for Path<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let axum::extract::Path(value) = axum::extract::Path::<T>::from_request_parts(parts, state)
+            .await
+            .map_err(|rejection: PathRejection| malformed(MessageKey::MalformedPath, rejection.body_text()))?;
+
+        Ok(Path(value))
+    }
+}
+
+fn malformed(key: MessageKey, reason: String) -> AppError {
+    AppError::Malformed { key, reason }
+}
@@ -1,29 +1,160 @@
 use axum::{
-    extract::{Request, State},
+    async_trait,
+    extract::{FromRequestParts, Request, State},
+    http::request::Parts,
     middleware::Next,
     response::Response,
 };
 use crate::errors::AppError;
-use crate::infrastructure::security::JwtService;
+use crate::i18n::MessageKey;
+use crate::utils::request_context::{self, RequestContext};
+use crate::AppState;
+
+/// 요청에서 사용할 그룹을 지정하는 헤더
+pub const GROUP_ID_HEADER: &str = "X-Group-Id";
+
+/// 인증된 요청의 사용자 정보
+///
+/// `auth_middleware`가 검증 후 request extensions에 넣어두며,
+/// 핸들러에서는 인자로 `user: AuthUser`를 받아 사용합니다.
+#[derive(Debug, Clone)]
+pub struct AuthUser {
+    pub user_id: i64,
+    pub email: String,
+    pub email_verified: bool,
+    /// 활성 그룹: `X-Group-Id` 헤더로 지정한 그룹, 없으면 사용자의 기본 그룹
+    pub group_id: Option<i64>,
+    /// `X-Group-Id` 헤더로 지정한 그룹 (멤버십 확인 완료, 기본 그룹으로 대체하지 않음)
+    pub requested_group_id: Option<i64>,
+    /// 토큰이 속한 로그인 세션 ID
+    pub session_id: String,
+}
+
+impl AuthUser {
+    /// 거래, 카테고리, 태그를 다룰 그룹: 쿼리/본문의 `group_id`, 없으면 `X-Group-Id` 헤더의 그룹
+    ///
+    /// 둘 다 없으면 개인 범위(`None`)이고, 둘이 다른 그룹이면 거부합니다.
+    pub fn scope_group_id(&self, group_id: Option<i64>) -> Result<Option<i64>, AppError> {
+        match (group_id, self.requested_group_id) {
+            (Some(explicit), Some(requested)) if explicit != requested => {
+                Err(AppError::Validation(MessageKey::GroupScopeMismatch))
+            }
+            (explicit, requested) => Ok(explicit.or(requested)),
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AuthUser
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts.extensions
+            .get::<AuthUser>()
+            .cloned()
+            .ok_or_else(|| AppError::Authentication(MessageKey::AuthenticationRequired))
+    }
+}
 
 pub async fn auth_middleware(
-    State(jwt_service): State<JwtService>,
+    State(state): State<AppState>,
     mut request: Request,
     next: Next,
 ) -> Result<Response, AppError> {
     let auth_header = request.headers()
         .get("Authorization")
         .and_then(|h| h.to_str().ok())
-        .ok_or_else(|| AppError::Authentication("Missing authorization header".to_string()))?;
+        .ok_or_else(|| AppError::Authentication(MessageKey::AuthenticationRequired))?;
 
     let token = auth_header.strip_prefix("Bearer ")
-        .ok_or_else(|| AppError::Authentication("Invalid authorization format".to_string()))?;
-
-    let claims = jwt_service.verify_token(token)?;
-    
-    // 사용자 정보를 request extensions에 추가
-    request.extensions_mut().insert(claims.user_id);
-    
-    Ok(next.run(request).await)
+        .ok_or_else(|| AppError::Authentication(MessageKey::AuthenticationRequired))?;
+
+    let claims = state.jwt_service.verify_access_token(token)?;
+
+    // 로그아웃되었거나 원격으로 폐기된 세션의 토큰은 거부
+    state.auth_service.ensure_session_active(claims.user_id, &claims.sid).await?;
+
+    // 토큰 발급 이후 변경된 그룹 정보를 반영하기 위해 사용자를 다시 조회
+    let user = state.auth_service.current_user(claims.user_id).await?;
+
+    let requested_group_id = requested_group_id(&request)?;
+    if let Some(group_id) = requested_group_id {
+        // 멤버가 아닌 그룹은 존재 여부도 노출하지 않음
+        state.group_service
+            .member_role(group_id, user.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::GroupNotFound))?;
+    }
+
+    let preferred_locale = user.preferred_locale();
+
+    request.extensions_mut().insert(AuthUser {
+        email_verified: user.is_email_verified(),
+        user_id: user.id,
+        email: user.email,
+        group_id: requested_group_id.or(user.group_id),
+        requested_group_id,
+        session_id: claims.sid,
+    });
+
+    // Accept-Language가 없으면 사용자 설정 언어로 응답
+    match request_context::current() {
+        Some(context) if context.locale.is_none() && preferred_locale.is_some() => {
+            let context = RequestContext { locale: preferred_locale, ..context };
+            Ok(request_context::scope(context, next.run(request)).await)
+        }
+        _ => Ok(next.run(request).await),
+    }
+}
+
+fn requested_group_id(request: &Request) -> Result<Option<i64>, AppError> {
+    let Some(value) = request.headers().get(GROUP_ID_HEADER) else {
+        return Ok(None);
+    };
+
+    value
+        .to_str()
+        .ok()
+        .and_then(|value| value.trim().parse::<i64>().ok())
+        .map(Some)
+        .ok_or(AppError::Validation(MessageKey::InvalidGroupHeader))
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(requested_group_id: Option<i64>) -> AuthUser {
+        AuthUser {
+            user_id: 1,
+            email: "user@example.com".to_string(),
+            email_verified: true,
+            group_id: requested_group_id.or(Some(9)),
+            requested_group_id,
+            session_id: "session".to_string(),
+        }
+    }
+
+    #[test]
+    fn scope_uses_group_header_when_request_has_no_group() {
+        assert_eq!(user(Some(5)).scope_group_id(None).unwrap(), Some(5));
+        assert_eq!(user(Some(5)).scope_group_id(Some(5)).unwrap(), Some(5));
+    }
+
+    #[test]
+    fn scope_ignores_default_group_without_header() {
+        assert_eq!(user(None).scope_group_id(None).unwrap(), None);
+        assert_eq!(user(None).scope_group_id(Some(7)).unwrap(), Some(7));
+    }
+
+    #[test]
+    fn scope_rejects_conflicting_groups() {
+        assert!(matches!(
+            user(Some(5)).scope_group_id(Some(7)),
+            Err(AppError::Validation(MessageKey::GroupScopeMismatch)),
+        ));
+    }
+}
@@ -0,0 +1,133 @@
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts},
+    http::{header, request::Parts, HeaderMap},
+};
+use crate::domain::models::DeviceInfo;
+use crate::errors::AppError;
+use crate::AppState;
+
+const MAX_USER_AGENT_LEN: usize = 255;
+
+/// 요청한 기기의 User-Agent와 IP
+///
+/// 전달 헤더(`Fly-Client-IP`, `X-Forwarded-For`, `X-Real-IP`)는 직접 연결한 상대가
+/// 신뢰하는 프록시(`TRUSTED_PROXIES`)일 때만 사용하고, 그 외에는 연결 주소를 사용합니다.
+#[derive(Debug, Clone, Default)]
+pub struct ClientInfo(pub DeviceInfo);
+
+#[async_trait]
+impl FromRequestParts<AppState> for ClientInfo {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let user_agent = header_value(&parts.headers, header::USER_AGENT.as_str())
+            .map(|ua| ua.chars().take(MAX_USER_AGENT_LEN).collect());
+
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let ip_address = peer
+            .map(|peer| client_ip(&parts.headers, peer, &state.trusted_proxies))
+            .map(|ip| ip.to_string());
+
+        Ok(ClientInfo(DeviceInfo {
+            user_agent,
+            ip_address,
+        }))
+    }
+}
+
+/// 신뢰하는 프록시 주소 목록 (IP 또는 CIDR)
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxies {
+    networks: Vec<(IpAddr, u8)>,
+}
+
+impl TrustedProxies {
+    /// 쉼표로 구분된 목록을 파싱합니다 (예: `10.0.0.0/8,fdaa::/16,127.0.0.1`).
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        let networks = value
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| {
+                parse_network(item)
+                    .ok_or_else(|| AppError::Configuration(format!("Invalid trusted proxy: {}", item)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { networks })
+    }
+
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        self.networks
+            .iter()
+            .any(|(network, prefix)| in_network(ip, *network, *prefix))
+    }
+}
+
+/// 상대가 신뢰하는 프록시면 전달 헤더의 클라이언트 IP, 아니면 상대 주소
+///
+/// `X-Forwarded-For`는 클라이언트가 앞부분을 임의로 채울 수 있으므로
+/// 오른쪽부터 신뢰하는 프록시를 건너뛴 첫 주소를 사용합니다.
+fn client_ip(headers: &HeaderMap, peer: IpAddr, trusted: &TrustedProxies) -> IpAddr {
+    if !trusted.contains(peer) {
+        return peer;
+    }
+
+    header_ip(headers, "Fly-Client-IP")
+        .or_else(|| {
+            header_value(headers, "X-Forwarded-For").and_then(|value| {
+                value
+                    .rsplit(',')
+                    .filter_map(|ip| ip.trim().parse::<IpAddr>().ok())
+                    .find(|ip| !trusted.contains(*ip))
+            })
+        })
+        .or_else(|| header_ip(headers, "X-Real-IP"))
+        .unwrap_or(peer)
+}
+
+fn parse_network(value: &str) -> Option<(IpAddr, u8)> {
+    let (addr, prefix) = match value.split_once('/') {
+        Some((addr, prefix)) => (addr.parse::<IpAddr>().ok()?, Some(prefix.parse::<u8>().ok()?)),
+        None => (value.parse::<IpAddr>().ok()?, None),
+    };
+    let max_prefix = if addr.is_ipv4() { 32 } else { 128 };
+    let prefix = prefix.unwrap_or(max_prefix);
+
+    (prefix <= max_prefix).then_some((addr, prefix))
+}
+
+fn in_network(ip: IpAddr, network: IpAddr, prefix: u8) -> bool {
+    match (ip, network) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => {
+            let mask = u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0);
+            u32::from(ip) & mask == u32::from(network) & mask
+        }
+        (IpAddr::V6(ip), IpAddr::V6(network)) => {
+            let mask = u128::MAX.checked_shl(128 - prefix as u32).unwrap_or(0);
+            u128::from(ip) & mask == u128::from(network) & mask
+        }
+        // IPv4 연결이 IPv6 주소(::ffff:a.b.c.d)로 들어온 경우
+        (IpAddr::V6(ip), IpAddr::V4(_)) => ip
+            .to_ipv4_mapped()
+            .is_some_and(|ip| in_network(IpAddr::V4(ip), network, prefix)),
+        (IpAddr::V4(_), IpAddr::V6(_)) => false,
+    }
+}
+
+fn header_ip(headers: &HeaderMap, name: &str) -> Option<IpAddr> {
+    header_value(headers, name).and_then(|value| value.trim().parse().ok())
+}
+
+fn header_value(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|h| h.to_str().ok())
+        .map(|v| v.to_string())
+}
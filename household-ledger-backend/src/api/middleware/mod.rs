@@ -1,4 +1,7 @@
 pub mod auth;
+pub mod client_info;
+pub mod request_context;
 
-pub use auth::auth_middleware;
-
+pub use auth::{auth_middleware, AuthUser};
+pub use client_info::{ClientInfo, TrustedProxies};
+pub use request_context::request_context_middleware;
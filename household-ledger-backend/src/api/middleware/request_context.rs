@@ -0,0 +1,46 @@
+use axum::{
+    extract::Request,
+    http::{header, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use crate::i18n::Locale;
+use crate::utils::request_context::{self, RequestContext};
+
+/// 요청 ID를 주고받는 헤더
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+const MAX_REQUEST_ID_LEN: usize = 64;
+
+/// 요청 ID와 응답 언어를 정해 요청 컨텍스트에 설정합니다.
+///
+/// 클라이언트나 프록시가 보낸 `X-Request-Id`가 있으면 그대로 사용하고,
+/// 응답 헤더에도 같은 값을 넣습니다. 언어는 `Accept-Language`에서 고릅니다.
+pub async fn request_context_middleware(request: Request, next: Next) -> Response {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty() && value.len() <= MAX_REQUEST_ID_LEN)
+        .map(str::to_string)
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    let locale = request
+        .headers()
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(Locale::from_accept_language);
+
+    let context = RequestContext {
+        request_id: request_id.clone(),
+        locale,
+    };
+    let mut response = request_context::scope(context, next.run(request)).await;
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+
+    response
+}
@@ -1,3 +1,4 @@
+pub mod extract;
 pub mod health;
 pub mod middleware;
 pub mod v2;
@@ -5,8 +6,7 @@ pub mod v2;
 use axum::Router;
 use crate::AppState;
 
-pub fn router() -> Router<AppState> {
+pub fn router(state: AppState) -> Router<AppState> {
     Router::new()
-        .nest("/api/v2", v2::router())
+        .nest("/api/v2", v2::router(state))
 }
-
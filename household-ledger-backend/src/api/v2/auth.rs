@@ -1,39 +1,61 @@
 use axum::{
     extract::State,
-    http::StatusCode,
-    response::Json,
-    routing::{post, Router},
+    routing::{delete, get, post, Router},
 };
-use crate::application::services::AuthService;
+use validator::Validate;
+use crate::api::extract::{Json, Path};
+use crate::api::middleware::{AuthUser, ClientInfo};
 use crate::schemas::{
     SignupRequest, LoginRequest, RefreshTokenRequest,
-    AuthResponse, RefreshTokenResponse,
+    AuthResponse, RefreshTokenResponse, UserResponse,
+    MessageResponse, RevokeSessionsResponse, SessionResponse,
+    ChangePasswordRequest, ForgotPasswordRequest, ResetPasswordRequest, VerifyEmailRequest,
 };
 use crate::errors::AppError;
+use crate::i18n::MessageKey;
 use crate::AppState;
 
+/// 인증 없이 접근 가능한 라우트
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/signup", post(signup))
         .route("/login", post(login))
         .route("/refresh", post(refresh))
+        .route("/forgot-password", post(forgot_password))
+        .route("/reset-password", post(reset_password))
+        .route("/verify-email", post(verify_email))
+}
+
+/// 로그인한 사용자 전용 라우트
+pub fn protected_router() -> Router<AppState> {
+    Router::new()
+        .route("/logout", post(logout))
+        .route("/change-password", post(change_password))
+        .route("/resend-verification", post(resend_verification))
+        .route("/sessions", get(list_sessions).delete(revoke_other_sessions))
+        .route("/sessions/:id", delete(revoke_session))
 }
 
 async fn signup(
     State(state): State<AppState>,
+    ClientInfo(device): ClientInfo,
     Json(payload): Json<SignupRequest>,
 ) -> Result<Json<AuthResponse>, AppError> {
     // 입력 검증
-    payload.validate()
-        .map_err(|e| AppError::Validation(format!("입력 검증 실패: {:?}", e)))?;
+    payload.validate()?;
 
     let (user, access_token, refresh_token) = state.auth_service.signup(
         payload.email,
         payload.password,
         payload.nickname,
         payload.invite_code,
+        device,
     ).await?;
-    
+
+    if let Err(e) = state.category_service.seed_default_categories(user.id, None).await {
+        tracing::error!(user_id = user.id, "기본 카테고리 생성 실패: {}", e);
+    }
+
     Ok(Json(AuthResponse {
         user: UserResponse::from(user),
         access_token,
@@ -43,15 +65,16 @@ async fn signup(
 
 async fn login(
     State(state): State<AppState>,
+    ClientInfo(device): ClientInfo,
     Json(payload): Json<LoginRequest>,
 ) -> Result<Json<AuthResponse>, AppError> {
     // 입력 검증
-    payload.validate()
-        .map_err(|e| AppError::Validation(format!("입력 검증 실패: {:?}", e)))?;
+    payload.validate()?;
 
     let (user, access_token, refresh_token) = state.auth_service.login(
         payload.email,
         payload.password,
+        device,
     ).await?;
     
     Ok(Json(AuthResponse {
@@ -63,10 +86,119 @@ async fn login(
 
 async fn refresh(
     State(state): State<AppState>,
+    ClientInfo(device): ClientInfo,
     Json(payload): Json<RefreshTokenRequest>,
 ) -> Result<Json<RefreshTokenResponse>, AppError> {
-    let access_token = state.auth_service.refresh_token(payload.refresh_token).await?;
+    let (access_token, refresh_token) = state.auth_service.refresh_token(payload.refresh_token, device).await?;
     
-    Ok(Json(RefreshTokenResponse { access_token }))
+    Ok(Json(RefreshTokenResponse {
+        access_token,
+        refresh_token,
+    }))
 }
 
+async fn forgot_password(
+    State(state): State<AppState>,
+    Json(payload): Json<ForgotPasswordRequest>,
+) -> Result<Json<MessageResponse>, AppError> {
+    payload.validate()?;
+
+    state.auth_service.forgot_password(&payload.email).await?;
+
+    // 가입 여부와 관계없이 같은 응답
+    Ok(Json(MessageResponse::new(MessageKey::PasswordResetRequested)))
+}
+
+async fn reset_password(
+    State(state): State<AppState>,
+    Json(payload): Json<ResetPasswordRequest>,
+) -> Result<Json<MessageResponse>, AppError> {
+    payload.validate()?;
+
+    state.auth_service.reset_password(&payload.token, &payload.new_password).await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::PasswordResetCompleted)))
+}
+
+/// 메일 링크에서 호출하므로 로그인 없이 토큰만으로 인증
+async fn verify_email(
+    State(state): State<AppState>,
+    Json(payload): Json<VerifyEmailRequest>,
+) -> Result<Json<UserResponse>, AppError> {
+    payload.validate()?;
+
+    let user = state.auth_service.verify_email(&payload.token).await?;
+
+    Ok(Json(UserResponse::from(user)))
+}
+
+async fn resend_verification(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<Json<MessageResponse>, AppError> {
+    state.auth_service.resend_verification_email(user.user_id).await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::VerificationEmailResent)))
+}
+
+async fn change_password(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<ChangePasswordRequest>,
+) -> Result<Json<MessageResponse>, AppError> {
+    payload.validate()?;
+
+    state.auth_service.change_password(
+        user.user_id,
+        &user.session_id,
+        &payload.current_password,
+        &payload.new_password,
+    ).await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::PasswordChanged)))
+}
+
+async fn logout(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<Json<MessageResponse>, AppError> {
+    state.auth_service.logout(user.user_id, &user.session_id).await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::LoggedOut)))
+}
+
+async fn list_sessions(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<Json<Vec<SessionResponse>>, AppError> {
+    let sessions = state.auth_service.list_sessions(user.user_id).await?;
+
+    Ok(Json(
+        sessions
+            .into_iter()
+            .map(|session| SessionResponse::new(session, &user.session_id))
+            .collect(),
+    ))
+}
+
+async fn revoke_session(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<String>,
+) -> Result<Json<MessageResponse>, AppError> {
+    state.auth_service.revoke_session(user.user_id, &id).await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::SessionLoggedOut)))
+}
+
+/// 현재 세션을 제외한 모든 기기에서 로그아웃
+async fn revoke_other_sessions(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<Json<RevokeSessionsResponse>, AppError> {
+    let revoked = state.auth_service
+        .revoke_other_sessions(user.user_id, &user.session_id)
+        .await?;
+
+    Ok(Json(RevokeSessionsResponse { revoked }))
+}
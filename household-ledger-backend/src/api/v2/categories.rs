@@ -0,0 +1,174 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    routing::{get, post, Router},
+};
+use validator::Validate;
+use crate::AppState;
+use crate::api::extract::{Json, Path, Query};
+use crate::api::middleware::AuthUser;
+use crate::domain::models::Category;
+use crate::schemas::{
+    CategoryCreateRequest, CategoryDefaultsRequest, CategoryDefaultsResponse, CategoryDeleteQuery,
+    CategoryListQuery, CategoryMergeRequest, CategoryMergeResponse, CategoryNodeResponse,
+    CategoryResponse, CategoryUpdateRequest, MessageResponse,
+};
+use crate::errors::AppError;
+use crate::i18n::MessageKey;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_categories).post(create_category))
+        .route("/tree", get(list_category_tree))
+        .route("/defaults/sync", post(sync_default_categories))
+        .route("/defaults/reset", post(reset_default_categories))
+        .route("/:id", get(get_category).put(update_category).delete(delete_category))
+        .route("/:id/merge", post(merge_category))
+}
+
+async fn list_categories(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Query(params): Query<CategoryListQuery>,
+) -> Result<Json<Vec<CategoryResponse>>, AppError> {
+    let group_id = user.scope_group_id(params.group_id)?;
+    let categories = state.category_service
+        .list_categories(user.user_id, group_id, params.transaction_type)
+        .await?;
+
+    Ok(Json(categories.into_iter().map(CategoryResponse::from).collect()))
+}
+
+async fn list_category_tree(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Query(params): Query<CategoryListQuery>,
+) -> Result<Json<Vec<CategoryNodeResponse>>, AppError> {
+    let group_id = user.scope_group_id(params.group_id)?;
+    let tree = state.category_service
+        .list_category_tree(user.user_id, group_id, params.transaction_type)
+        .await?;
+
+    Ok(Json(tree.into_iter().map(CategoryNodeResponse::from).collect()))
+}
+
+async fn create_category(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<CategoryCreateRequest>,
+) -> Result<(StatusCode, Json<CategoryResponse>), AppError> {
+    payload.validate()?;
+
+    let now = chrono::Utc::now().naive_utc();
+    let category = Category {
+        id: 0,
+        group_id: user.scope_group_id(payload.group_id)?,
+        created_by: user.user_id,
+        parent_id: payload.parent_id,
+        name: payload.name,
+        transaction_type: payload.transaction_type,
+        color: payload.color,
+        is_default: false,
+        default_key: None,
+        budget_amount: payload.budget_amount,
+        created_at: now,
+        updated_at: now,
+    };
+
+    let created = state.category_service.create_category(user.user_id, category).await?;
+
+    Ok((StatusCode::CREATED, Json(CategoryResponse::from(created))))
+}
+
+async fn get_category(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+) -> Result<Json<CategoryResponse>, AppError> {
+    let category = state.category_service.get_category(user.user_id, id).await?;
+
+    Ok(Json(CategoryResponse::from(category)))
+}
+
+async fn update_category(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+    Json(payload): Json<CategoryUpdateRequest>,
+) -> Result<Json<CategoryResponse>, AppError> {
+    payload.validate()?;
+
+    let existing = state.category_service.get_category(user.user_id, id).await?;
+    let category = Category {
+        parent_id: payload.parent_id,
+        name: payload.name,
+        color: payload.color,
+        budget_amount: payload.budget_amount,
+        ..existing
+    };
+
+    let updated = state.category_service.update_category(user.user_id, category).await?;
+
+    Ok(Json(CategoryResponse::from(updated)))
+}
+
+async fn delete_category(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+    Query(params): Query<CategoryDeleteQuery>,
+) -> Result<Json<MessageResponse>, AppError> {
+    state.category_service
+        .delete_category(user.user_id, id, params.reassign_to)
+        .await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::CategoryDeleted)))
+}
+
+async fn merge_category(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+    Json(payload): Json<CategoryMergeRequest>,
+) -> Result<Json<CategoryMergeResponse>, AppError> {
+    let (category, summary) = state.category_service
+        .merge_categories(user.user_id, id, payload.target_category_id)
+        .await?;
+
+    Ok(Json(CategoryMergeResponse {
+        category: CategoryResponse::from(category),
+        moved: summary.into(),
+    }))
+}
+
+async fn sync_default_categories(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<CategoryDefaultsRequest>,
+) -> Result<Json<CategoryDefaultsResponse>, AppError> {
+    let group_id = user.scope_group_id(payload.group_id)?;
+    let result = state.category_service
+        .sync_default_categories(user.user_id, group_id)
+        .await?;
+    let tree = state.category_service
+        .list_category_tree(user.user_id, group_id, None)
+        .await?;
+
+    Ok(Json(CategoryDefaultsResponse::new(result, tree)))
+}
+
+async fn reset_default_categories(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<CategoryDefaultsRequest>,
+) -> Result<Json<CategoryDefaultsResponse>, AppError> {
+    let group_id = user.scope_group_id(payload.group_id)?;
+    let result = state.category_service
+        .reset_default_categories(user.user_id, group_id)
+        .await?;
+    let tree = state.category_service
+        .list_category_tree(user.user_id, group_id, None)
+        .await?;
+
+    Ok(Json(CategoryDefaultsResponse::new(result, tree)))
+}
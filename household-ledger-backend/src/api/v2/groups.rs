@@ -0,0 +1,220 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    routing::{delete, get, post, put, Router},
+};
+use validator::Validate;
+use crate::AppState;
+use crate::api::extract::{Json, OptionalJson, Path};
+use crate::api::middleware::AuthUser;
+use crate::schemas::{
+    CreateInviteRequest, GroupCreateRequest, GroupDetailResponse, GroupMemberResponse, GroupResponse,
+    GroupUpdateRequest, InviteResponse, JoinGroupRequest, MessageResponse, TransferOwnershipRequest,
+    UpdateMemberRoleRequest,
+};
+use crate::errors::AppError;
+use crate::i18n::MessageKey;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_groups).post(create_group))
+        .route("/join", post(join_group))
+        .route("/leave", post(leave_group))
+        .route("/:id", get(get_group).put(update_group).delete(delete_group))
+        .route("/:id/leave", post(leave_group_by_id))
+        .route("/:id/transfer", post(transfer_ownership))
+        .route("/:id/members", get(list_members))
+        .route("/:id/members/:user_id", delete(remove_member))
+        .route("/:id/members/:user_id/role", put(update_member_role))
+        .route("/:id/invite", post(create_invite))
+        .route("/:id/invites", get(list_invites))
+        .route("/:id/invites/:invite_id", delete(revoke_invite))
+}
+
+async fn list_groups(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<Json<Vec<GroupResponse>>, AppError> {
+    let groups = state.group_service.list_groups(user.user_id).await?;
+
+    Ok(Json(groups.into_iter().map(GroupResponse::from).collect()))
+}
+
+async fn create_group(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<GroupCreateRequest>,
+) -> Result<(StatusCode, Json<GroupResponse>), AppError> {
+    payload.validate()?;
+
+    let group = state.group_service.create_group(payload.name, user.user_id).await?;
+
+    if let Err(e) = state
+        .category_service
+        .seed_default_categories(user.user_id, Some(group.id))
+        .await
+    {
+        tracing::error!(group_id = group.id, "그룹 기본 카테고리 생성 실패: {}", e);
+    }
+
+    Ok((StatusCode::CREATED, Json(GroupResponse::from(group))))
+}
+
+async fn get_group(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+) -> Result<Json<GroupDetailResponse>, AppError> {
+    let (group, member_count) = state.group_service.get_group_detail(id, user.user_id).await?;
+
+    Ok(Json(GroupDetailResponse::new(group, member_count)))
+}
+
+async fn update_group(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+    Json(payload): Json<GroupUpdateRequest>,
+) -> Result<Json<GroupResponse>, AppError> {
+    payload.validate()?;
+
+    let group = state.group_service.rename_group(id, user.user_id, payload.name).await?;
+
+    Ok(Json(GroupResponse::from(group)))
+}
+
+async fn delete_group(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+) -> Result<Json<MessageResponse>, AppError> {
+    state.group_service.delete_group(id, user.user_id).await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::GroupDeleted)))
+}
+
+/// 활성 그룹(`X-Group-Id` 헤더 또는 기본 그룹)에서 탈퇴
+async fn leave_group(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<Json<MessageResponse>, AppError> {
+    let group_id = user.group_id
+        .ok_or_else(|| AppError::Validation(MessageKey::NoActiveGroup))?;
+
+    state.group_service.leave_group(group_id, user.user_id).await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::GroupLeft)))
+}
+
+async fn leave_group_by_id(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+) -> Result<Json<MessageResponse>, AppError> {
+    state.group_service.leave_group(id, user.user_id).await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::GroupLeft)))
+}
+
+async fn transfer_ownership(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+    Json(payload): Json<TransferOwnershipRequest>,
+) -> Result<Json<GroupResponse>, AppError> {
+    let group = state.group_service
+        .transfer_ownership(id, user.user_id, payload.new_owner_id)
+        .await?;
+
+    Ok(Json(GroupResponse::from(group)))
+}
+
+async fn list_members(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<GroupMemberResponse>>, AppError> {
+    let members = state.group_service.list_members(id, user.user_id).await?;
+
+    Ok(Json(
+        members
+            .into_iter()
+            .map(|(member, member_user)| GroupMemberResponse::new(member, member_user))
+            .collect(),
+    ))
+}
+
+async fn update_member_role(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((id, member_id)): Path<(i64, i64)>,
+    Json(payload): Json<UpdateMemberRoleRequest>,
+) -> Result<Json<MessageResponse>, AppError> {
+    state.group_service
+        .change_member_role(id, user.user_id, member_id, payload.role)
+        .await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::MemberRoleChanged)))
+}
+
+async fn remove_member(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((id, member_id)): Path<(i64, i64)>,
+) -> Result<Json<MessageResponse>, AppError> {
+    state.group_service.remove_member(id, user.user_id, member_id).await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::MemberRemoved)))
+}
+
+async fn create_invite(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(group_id): Path<i64>,
+    OptionalJson(payload): OptionalJson<CreateInviteRequest>,
+) -> Result<(StatusCode, Json<InviteResponse>), AppError> {
+    // 본문이 없을 때만 기본값 (해석할 수 없는 본문은 400)
+    let payload = payload.unwrap_or_default();
+    payload.validate()?;
+
+    let invite = state.group_service.create_invite(
+        group_id,
+        user.user_id,
+        payload.expires_in_hours,
+        payload.max_uses,
+    ).await?;
+
+    Ok((StatusCode::CREATED, Json(InviteResponse::from(invite))))
+}
+
+async fn list_invites(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(group_id): Path<i64>,
+) -> Result<Json<Vec<InviteResponse>>, AppError> {
+    let invites = state.group_service.list_invites(group_id, user.user_id).await?;
+
+    Ok(Json(invites.into_iter().map(InviteResponse::from).collect()))
+}
+
+async fn revoke_invite(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((group_id, invite_id)): Path<(i64, i64)>,
+) -> Result<Json<MessageResponse>, AppError> {
+    state.group_service.revoke_invite(group_id, invite_id, user.user_id).await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::InviteRevoked)))
+}
+
+async fn join_group(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<JoinGroupRequest>,
+) -> Result<Json<GroupResponse>, AppError> {
+    payload.validate()?;
+
+    let group = state.group_service.join_group(user.user_id, &payload.code).await?;
+
+    Ok(Json(GroupResponse::from(group)))
+}
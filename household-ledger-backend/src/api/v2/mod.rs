@@ -1,13 +1,24 @@
 pub mod auth;
+pub mod categories;
+pub mod groups;
+pub mod tags;
 pub mod transactions;
 
-use axum::Router;
+use axum::{middleware, Router};
+use crate::api::middleware::auth_middleware;
 use crate::AppState;
 
-pub fn router() -> Router<AppState> {
+pub fn router(state: AppState) -> Router<AppState> {
+    // signup, login, refresh를 제외한 모든 라우트는 인증 필요
+    let protected = Router::new()
+        .nest("/auth", auth::protected_router())
+        .nest("/categories", categories::router())
+        .nest("/groups", groups::router())
+        .nest("/tags", tags::router())
+        .nest("/transactions", transactions::router())
+        .route_layer(middleware::from_fn_with_state(state, auth_middleware));
+
     Router::new()
         .nest("/auth", auth::router())
-        .nest("/transactions", transactions::router())
+        .merge(protected)
 }
-
-
@@ -0,0 +1,105 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    routing::{get, post, Router},
+};
+use validator::Validate;
+use crate::AppState;
+use crate::api::extract::{Json, Path, Query};
+use crate::api::middleware::AuthUser;
+use crate::domain::models::Tag;
+use crate::schemas::{
+    MessageResponse, TagCreateRequest, TagListQuery, TagMergeRequest, TagMergeResponse, TagResponse,
+    TagUpdateRequest,
+};
+use crate::errors::AppError;
+use crate::i18n::MessageKey;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_tags).post(create_tag))
+        .route("/:id", get(get_tag).put(update_tag).delete(delete_tag))
+        .route("/:id/merge", post(merge_tag))
+}
+
+async fn list_tags(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Query(params): Query<TagListQuery>,
+) -> Result<Json<Vec<TagResponse>>, AppError> {
+    let group_id = user.scope_group_id(params.group_id)?;
+    let tags = state.tag_service.list_tags(user.user_id, group_id).await?;
+
+    Ok(Json(tags.into_iter().map(TagResponse::from).collect()))
+}
+
+async fn create_tag(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<TagCreateRequest>,
+) -> Result<(StatusCode, Json<TagResponse>), AppError> {
+    payload.validate()?;
+
+    let now = chrono::Utc::now().naive_utc();
+    let tag = Tag {
+        id: 0,
+        group_id: user.scope_group_id(payload.group_id)?,
+        created_by: user.user_id,
+        name: payload.name,
+        created_at: now,
+        updated_at: now,
+    };
+
+    let created = state.tag_service.create_tag(user.user_id, tag).await?;
+
+    Ok((StatusCode::CREATED, Json(TagResponse::from(created))))
+}
+
+async fn get_tag(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+) -> Result<Json<TagResponse>, AppError> {
+    let tag = state.tag_service.get_tag(user.user_id, id).await?;
+
+    Ok(Json(TagResponse::from(tag)))
+}
+
+async fn update_tag(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+    Json(payload): Json<TagUpdateRequest>,
+) -> Result<Json<TagResponse>, AppError> {
+    payload.validate()?;
+
+    let updated = state.tag_service.rename_tag(user.user_id, id, &payload.name).await?;
+
+    Ok(Json(TagResponse::from(updated)))
+}
+
+async fn delete_tag(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+) -> Result<Json<MessageResponse>, AppError> {
+    state.tag_service.delete_tag(user.user_id, id).await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::TagDeleted)))
+}
+
+async fn merge_tag(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+    Json(payload): Json<TagMergeRequest>,
+) -> Result<Json<TagMergeResponse>, AppError> {
+    let (tag, moved_transactions) = state.tag_service
+        .merge_tags(user.user_id, id, payload.target_tag_id)
+        .await?;
+
+    Ok(Json(TagMergeResponse {
+        tag: TagResponse::from(tag),
+        moved_transactions,
+    }))
+}
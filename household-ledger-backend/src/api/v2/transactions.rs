@@ -1,56 +1,275 @@
 use axum::{
-    extract::{Path, Query, State},
-    response::Json,
-    routing::{get, post, put, delete, Router},
+    extract::State,
+    http::StatusCode,
+    routing::{get, post, Router},
 };
+use validator::Validate;
 use crate::AppState;
-use crate::schemas::transaction::{TransactionCreateRequest, TransactionResponse};
+use crate::api::extract::{Json, Path, Query};
+use crate::api::middleware::AuthUser;
+use crate::domain::models::{BulkTransactionOp, Transaction, TransactionCursor, TransactionPatch};
+use crate::schemas::{
+    BulkCreateTransactionsRequest, CategoryStatisticsQuery, CategoryStatisticsResponse,
+    CategoryTotalResponse, BulkDeleteTransactionsRequest, BulkPatchTransactionsRequest,
+    BulkTransactionResponse, MessageResponse, QuickAddTransactionRequest, QuickAddTransactionResponse,
+    TransactionCreateRequest, TransactionListQuery, TransactionListResponse,
+    TransactionResponse, TransactionSearchQuery, TransactionSearchResponse, TransactionSearchResult,
+    TransactionUpdateRequest,
+};
+use crate::utils::search::search_terms;
 use crate::errors::AppError;
+use crate::i18n::MessageKey;
 
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", get(list_transactions).post(create_transaction))
+        .route("/search", get(search_transactions))
+        .route("/statistics/categories", get(category_statistics))
+        .route("/quick-add", post(quick_add_transaction))
+        .route(
+            "/bulk",
+            post(bulk_create_transactions)
+                .patch(bulk_patch_transactions)
+                .delete(bulk_delete_transactions),
+        )
         .route("/:id", get(get_transaction).put(update_transaction).delete(delete_transaction))
 }
 
 async fn list_transactions(
-    State(_state): State<AppState>,
-    Query(_params): Query<std::collections::HashMap<String, String>>,
-) -> Result<Json<Vec<TransactionResponse>>, AppError> {
-    // TODO: 구현
-    Ok(Json(vec![]))
+    State(state): State<AppState>,
+    user: AuthUser,
+    Query(params): Query<TransactionListQuery>,
+) -> Result<Json<TransactionListResponse>, AppError> {
+    params.validate()?;
+
+    let cursor = params.cursor
+        .as_deref()
+        .map(|cursor| TransactionCursor::decode(cursor).ok_or(AppError::Validation(MessageKey::InvalidCursor)))
+        .transpose()?;
+
+    let page = state.transaction_service.list_transactions(
+        user.user_id,
+        user.scope_group_id(params.group_id)?,
+        params.filter(),
+        params.sort(),
+        cursor,
+        params.limit,
+        params.include_total,
+    ).await?;
+
+    let ids: Vec<i64> = page.items.iter().map(|tx| tx.id).collect();
+    let details = state.transaction_service.find_details(&ids).await?;
+
+    Ok(Json(TransactionListResponse::new(page, params.limit, details)))
+}
+
+async fn search_transactions(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Query(params): Query<TransactionSearchQuery>,
+) -> Result<Json<TransactionSearchResponse>, AppError> {
+    params.validate()?;
+
+    let terms = search_terms(&params.q);
+    let group_id = user.scope_group_id(params.group_id)?;
+    let hits = state.transaction_service
+        .search_transactions(user.user_id, group_id, &terms, params.limit)
+        .await?;
+
+    Ok(Json(TransactionSearchResponse {
+        items: hits
+            .into_iter()
+            .map(|hit| TransactionSearchResult::new(hit, &terms))
+            .collect(),
+    }))
+}
+
+async fn category_statistics(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Query(params): Query<CategoryStatisticsQuery>,
+) -> Result<Json<CategoryStatisticsResponse>, AppError> {
+    params.validate()?;
+
+    let group_id = user.scope_group_id(params.group_id)?;
+    let totals = state.transaction_service
+        .category_statistics(user.user_id, group_id, params.filter(), params.rollup)
+        .await?;
+
+    Ok(Json(CategoryStatisticsResponse {
+        items: totals.into_iter().map(CategoryTotalResponse::from).collect(),
+    }))
 }
 
 async fn create_transaction(
-    State(_state): State<AppState>,
-    Json(_payload): Json<TransactionCreateRequest>,
-) -> Result<Json<TransactionResponse>, AppError> {
-    // TODO: 구현
-    Err(AppError::Internal("Not implemented yet".to_string()))
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<TransactionCreateRequest>,
+) -> Result<(StatusCode, Json<TransactionResponse>), AppError> {
+    payload.validate()?;
+
+    let (mut transaction, links) = payload.into_parts(user.user_id);
+    transaction.group_id = user.scope_group_id(transaction.group_id)?;
+    let created = state.transaction_service
+        .create_transaction(user.user_id, transaction, links)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(transaction_response(&state, created).await?)))
+}
+
+async fn quick_add_transaction(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<QuickAddTransactionRequest>,
+) -> Result<(StatusCode, Json<QuickAddTransactionResponse>), AppError> {
+    payload.validate()?;
+
+    let now = chrono::Utc::now().naive_utc();
+    let transaction = Transaction {
+        id: 0,
+        group_id: user.scope_group_id(payload.group_id)?,
+        owner_user_id: user.user_id,
+        transaction_type: payload.transaction_type,
+        date: payload.date,
+        amount: payload.amount,
+        currency_code: payload.currency_code,
+        original_amount: payload.original_amount,
+        category_id: None,
+        recurring_rule_id: None,
+        receipt_id: None,
+        merchant: payload.merchant,
+        memo: payload.memo,
+        created_at: now,
+        updated_at: now,
+    };
+
+    let added = state.transaction_service
+        .quick_add(user.user_id, transaction, &payload.category_name, payload.tag_ids)
+        .await?;
+
+    let transaction_id = added.transaction.id;
+    let mut response = QuickAddTransactionResponse::from(added);
+    let details = state.transaction_service
+        .find_details(&[transaction_id])
+        .await?
+        .remove(&transaction_id)
+        .unwrap_or_default();
+    response.transaction = response.transaction.with_details(details);
+
+    Ok((StatusCode::CREATED, Json(response)))
 }
 
 async fn get_transaction(
-    State(_state): State<AppState>,
-    Path(_id): Path<i64>,
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
 ) -> Result<Json<TransactionResponse>, AppError> {
-    // TODO: 구현
-    Err(AppError::Internal("Not implemented yet".to_string()))
+    let transaction = state.transaction_service.get_transaction(user.user_id, id).await?;
+
+    Ok(Json(transaction_response(&state, transaction).await?))
 }
 
 async fn update_transaction(
-    State(_state): State<AppState>,
-    Path(_id): Path<i64>,
-    Json(_payload): Json<TransactionCreateRequest>,
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+    Json(payload): Json<TransactionUpdateRequest>,
 ) -> Result<Json<TransactionResponse>, AppError> {
-    // TODO: 구현
-    Err(AppError::Internal("Not implemented yet".to_string()))
+    payload.validate()?;
+
+    let mut transaction = state.transaction_service.get_transaction(user.user_id, id).await?;
+    let links = TransactionPatch::from(payload).apply(&mut transaction);
+
+    let updated = state.transaction_service
+        .update_transaction(user.user_id, transaction, links)
+        .await?;
+
+    Ok(Json(transaction_response(&state, updated).await?))
 }
 
 async fn delete_transaction(
-    State(_state): State<AppState>,
-    Path(_id): Path<i64>,
-) -> Result<(), AppError> {
-    // TODO: 구현
-    Err(AppError::Internal("Not implemented yet".to_string()))
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<i64>,
+) -> Result<Json<MessageResponse>, AppError> {
+    state.transaction_service.delete_transaction(user.user_id, id).await?;
+
+    Ok(Json(MessageResponse::new(MessageKey::TransactionDeleted)))
+}
+
+async fn bulk_create_transactions(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<BulkCreateTransactionsRequest>,
+) -> Result<Json<BulkTransactionResponse>, AppError> {
+    payload.validate()?;
+
+    // 항목별 입력 오류는 요청 전체를 거부하지 않고 해당 항목의 결과로 돌려줌
+    let items: Vec<Result<BulkTransactionOp, AppError>> = payload.items
+        .into_iter()
+        .map(|item| {
+            item.validate()?;
+            let (mut transaction, links) = item.into_parts(user.user_id);
+            transaction.group_id = user.scope_group_id(transaction.group_id)?;
+            Ok(BulkTransactionOp::Create { transaction, links })
+        })
+        .collect();
+
+    let outcomes = state.transaction_service.bulk_apply(user.user_id, items, payload.mode).await?;
+
+    Ok(Json(BulkTransactionResponse::new(payload.mode, outcomes)))
+}
+
+async fn bulk_patch_transactions(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<BulkPatchTransactionsRequest>,
+) -> Result<Json<BulkTransactionResponse>, AppError> {
+    payload.validate()?;
+
+    let items: Vec<Result<BulkTransactionOp, AppError>> = payload.items
+        .into_iter()
+        .map(|item| {
+            item.changes.validate()?;
+            Ok(BulkTransactionOp::Patch {
+                id: item.id,
+                patch: TransactionPatch::from(item.changes),
+            })
+        })
+        .collect();
+
+    let outcomes = state.transaction_service.bulk_apply(user.user_id, items, payload.mode).await?;
+
+    Ok(Json(BulkTransactionResponse::new(payload.mode, outcomes)))
 }
 
+async fn bulk_delete_transactions(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<BulkDeleteTransactionsRequest>,
+) -> Result<Json<BulkTransactionResponse>, AppError> {
+    payload.validate()?;
+
+    let items: Vec<Result<BulkTransactionOp, AppError>> = payload.ids
+        .into_iter()
+        .map(|id| Ok(BulkTransactionOp::Delete(id)))
+        .collect();
+
+    let outcomes = state.transaction_service.bulk_apply(user.user_id, items, payload.mode).await?;
+
+    Ok(Json(BulkTransactionResponse::new(payload.mode, outcomes)))
+}
+
+/// 거래 한 건의 응답에 분할 항목과 태그를 채웁니다.
+async fn transaction_response(
+    state: &AppState,
+    transaction: Transaction,
+) -> Result<TransactionResponse, AppError> {
+    let details = state.transaction_service
+        .find_details(&[transaction.id])
+        .await?
+        .remove(&transaction.id)
+        .unwrap_or_default();
+
+    Ok(TransactionResponse::from(transaction).with_details(details))
+}
@@ -1,2 +1,3 @@
+pub mod policies;
 pub mod services;
 
@@ -0,0 +1,68 @@
+use crate::domain::models::User;
+use crate::errors::AppError;
+use crate::i18n::MessageKey;
+
+/// 이메일 미인증 계정에 제한할 수 있는 기능
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestrictedAction {
+    CreateGroup,
+    JoinGroup,
+    CreateInvite,
+}
+
+impl RestrictedAction {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "create_group" => Some(Self::CreateGroup),
+            "join_group" => Some(Self::JoinGroup),
+            "create_invite" => Some(Self::CreateInvite),
+            _ => None,
+        }
+    }
+}
+
+/// 이메일 미인증 계정이 사용할 수 없는 기능 목록
+#[derive(Debug, Clone)]
+pub struct EmailVerificationPolicy {
+    restricted: Vec<RestrictedAction>,
+}
+
+impl Default for EmailVerificationPolicy {
+    /// 그룹 참여와 초대 코드 생성은 인증 후에만 허용
+    fn default() -> Self {
+        Self::new(vec![RestrictedAction::JoinGroup, RestrictedAction::CreateInvite])
+    }
+}
+
+impl EmailVerificationPolicy {
+    pub fn new(restricted: Vec<RestrictedAction>) -> Self {
+        Self { restricted }
+    }
+
+    /// 쉼표로 구분된 목록을 파싱합니다 (예: `join_group,create_invite`). `none`이면 제한 없음.
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        if value.trim() == "none" {
+            return Ok(Self::new(Vec::new()));
+        }
+
+        let restricted = value
+            .split(',')
+            .filter(|item| !item.trim().is_empty())
+            .map(|item| {
+                RestrictedAction::parse(item).ok_or_else(|| {
+                    AppError::Configuration(format!("Unknown restricted action: {}", item.trim()))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::new(restricted))
+    }
+
+    pub fn ensure_allowed(&self, user: &User, action: RestrictedAction) -> Result<(), AppError> {
+        if !user.is_email_verified() && self.restricted.contains(&action) {
+            return Err(AppError::Forbidden(MessageKey::EmailVerificationRequired));
+        }
+
+        Ok(())
+    }
+}
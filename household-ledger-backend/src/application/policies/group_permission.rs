@@ -0,0 +1,243 @@
+use crate::domain::models::GroupRole;
+use crate::domain::repositories::GroupMemberRepository;
+use crate::errors::AppError;
+use crate::i18n::MessageKey;
+
+/// 그룹 안에서 수행하는 작업
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupPermission {
+    /// 그룹 정보, 멤버, 거래 조회
+    ViewGroup,
+    CreateTransaction,
+    /// 본인이 작성한 거래 수정/삭제
+    ManageOwnTransactions,
+    /// 다른 멤버가 작성한 거래 수정/삭제
+    ManageAllTransactions,
+    ManageCategories,
+    /// 그룹 태그 이름 변경, 병합, 삭제 (생성은 거래 작성 권한)
+    ManageTags,
+    ManageBudgets,
+    ManageInvites,
+    /// 멤버 역할 변경 및 내보내기
+    ManageMembers,
+    UpdateGroup,
+    TransferOwnership,
+    DeleteGroup,
+}
+
+impl GroupPermission {
+    /// 작업에 필요한 최소 역할
+    fn minimum_role(self) -> GroupRole {
+        match self {
+            GroupPermission::ViewGroup => GroupRole::Viewer,
+            GroupPermission::CreateTransaction
+            | GroupPermission::ManageOwnTransactions => GroupRole::Editor,
+            GroupPermission::ManageAllTransactions
+            | GroupPermission::ManageCategories
+            | GroupPermission::ManageTags
+            | GroupPermission::ManageBudgets
+            | GroupPermission::ManageInvites
+            | GroupPermission::ManageMembers => GroupRole::Admin,
+            GroupPermission::UpdateGroup
+            | GroupPermission::TransferOwnership
+            | GroupPermission::DeleteGroup => GroupRole::Owner,
+        }
+    }
+}
+
+/// 그룹 역할별 권한 정책
+pub struct GroupPermissionPolicy;
+
+impl GroupPermissionPolicy {
+    pub fn allows(role: GroupRole, permission: GroupPermission) -> bool {
+        role.level() >= permission.minimum_role().level()
+    }
+
+    pub fn ensure(role: GroupRole, permission: GroupPermission) -> Result<(), AppError> {
+        if !Self::allows(role, permission) {
+            return Err(AppError::Forbidden(MessageKey::PermissionDenied));
+        }
+
+        Ok(())
+    }
+
+    /// 그룹 멤버의 역할로 권한을 확인하고 역할을 반환합니다. 멤버가 아니면 그룹이 없는 것으로 취급합니다.
+    pub async fn authorize(
+        member_repo: &dyn GroupMemberRepository,
+        group_id: i64,
+        user_id: i64,
+        permission: GroupPermission,
+    ) -> Result<GroupRole, AppError> {
+        let role = member_repo
+            .find_role(group_id, user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::GroupNotFound))?;
+
+        Self::ensure(role, permission)?;
+
+        Ok(role)
+    }
+
+    /// `actor`가 `target` 역할의 멤버를 `new_role`로 바꾸거나 내보낼 수 있는지 확인합니다.
+    ///
+    /// 소유자 역할은 소유권 이전으로만 바뀌며, 관리자는 자신보다 낮은 역할만 다룰 수 있습니다.
+    pub fn ensure_can_manage_member(
+        actor: GroupRole,
+        target: GroupRole,
+        new_role: Option<GroupRole>,
+    ) -> Result<(), AppError> {
+        Self::ensure(actor, GroupPermission::ManageMembers)?;
+
+        if target == GroupRole::Owner || new_role == Some(GroupRole::Owner) {
+            return Err(AppError::Forbidden(MessageKey::OwnerRoleTransferOnly));
+        }
+        if target.level() >= actor.level() || new_role.is_some_and(|role| role.level() >= actor.level()) {
+            return Err(AppError::Forbidden(MessageKey::CannotManageEqualOrHigherRole));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use async_trait::async_trait;
+    use crate::domain::models::GroupMember;
+    use super::*;
+
+    const ALL_ROLES: [GroupRole; 4] = [
+        GroupRole::Viewer,
+        GroupRole::Editor,
+        GroupRole::Admin,
+        GroupRole::Owner,
+    ];
+
+    /// `(group_id, user_id)`별 역할만 돌려주는 저장소 (나머지 메서드는 빈 결과)
+    struct Roles(HashMap<(i64, i64), GroupRole>);
+
+    #[async_trait]
+    impl GroupMemberRepository for Roles {
+        async fn is_member(&self, group_id: i64, user_id: i64) -> Result<bool, AppError> {
+            Ok(self.0.contains_key(&(group_id, user_id)))
+        }
+
+        async fn find_role(&self, group_id: i64, user_id: i64) -> Result<Option<GroupRole>, AppError> {
+            Ok(self.0.get(&(group_id, user_id)).copied())
+        }
+
+        async fn find_by_group(&self, _group_id: i64) -> Result<Vec<GroupMember>, AppError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_by_user(&self, _user_id: i64) -> Result<Vec<GroupMember>, AppError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_role(&self, _group_id: i64, _user_id: i64, _role: GroupRole) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        async fn remove(&self, _group_id: i64, _user_id: i64) -> Result<bool, AppError> {
+            Ok(false)
+        }
+    }
+
+    fn allowed_roles(permission: GroupPermission) -> Vec<GroupRole> {
+        ALL_ROLES
+            .into_iter()
+            .filter(|role| GroupPermissionPolicy::allows(*role, permission))
+            .collect()
+    }
+
+    #[test]
+    fn allows_minimum_role_and_above() {
+        use GroupRole::{Admin, Editor, Owner, Viewer};
+
+        assert_eq!(allowed_roles(GroupPermission::ViewGroup), vec![Viewer, Editor, Admin, Owner]);
+        assert_eq!(allowed_roles(GroupPermission::CreateTransaction), vec![Editor, Admin, Owner]);
+        assert_eq!(allowed_roles(GroupPermission::ManageOwnTransactions), vec![Editor, Admin, Owner]);
+        assert_eq!(allowed_roles(GroupPermission::ManageAllTransactions), vec![Admin, Owner]);
+        assert_eq!(allowed_roles(GroupPermission::ManageBudgets), vec![Admin, Owner]);
+        assert_eq!(allowed_roles(GroupPermission::ManageMembers), vec![Admin, Owner]);
+        assert_eq!(allowed_roles(GroupPermission::TransferOwnership), vec![Owner]);
+        assert_eq!(allowed_roles(GroupPermission::DeleteGroup), vec![Owner]);
+    }
+
+    #[test]
+    fn ensure_returns_forbidden() {
+        assert!(GroupPermissionPolicy::ensure(GroupRole::Admin, GroupPermission::ManageTags).is_ok());
+        assert!(matches!(
+            GroupPermissionPolicy::ensure(GroupRole::Editor, GroupPermission::ManageTags),
+            Err(AppError::Forbidden(MessageKey::PermissionDenied)),
+        ));
+    }
+
+    #[tokio::test]
+    async fn authorize_returns_member_role() {
+        let repo = Roles(HashMap::from([((1, 10), GroupRole::Editor)]));
+
+        let role = GroupPermissionPolicy::authorize(&repo, 1, 10, GroupPermission::CreateTransaction).await;
+
+        assert_eq!(role.unwrap(), GroupRole::Editor);
+    }
+
+    #[tokio::test]
+    async fn authorize_rejects_insufficient_role() {
+        let repo = Roles(HashMap::from([((1, 10), GroupRole::Viewer)]));
+
+        let result = GroupPermissionPolicy::authorize(&repo, 1, 10, GroupPermission::CreateTransaction).await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(MessageKey::PermissionDenied))));
+    }
+
+    #[tokio::test]
+    async fn authorize_hides_groups_of_non_members() {
+        let repo = Roles(HashMap::from([((1, 10), GroupRole::Owner)]));
+
+        let other_user = GroupPermissionPolicy::authorize(&repo, 1, 20, GroupPermission::ViewGroup).await;
+        let other_group = GroupPermissionPolicy::authorize(&repo, 2, 10, GroupPermission::ViewGroup).await;
+
+        assert!(matches!(other_user, Err(AppError::NotFound(MessageKey::GroupNotFound))));
+        assert!(matches!(other_group, Err(AppError::NotFound(MessageKey::GroupNotFound))));
+    }
+
+    #[test]
+    fn admin_manages_only_lower_roles() {
+        use GroupRole::{Admin, Editor, Viewer};
+
+        assert!(GroupPermissionPolicy::ensure_can_manage_member(Admin, Editor, Some(Viewer)).is_ok());
+        assert!(GroupPermissionPolicy::ensure_can_manage_member(Admin, Viewer, None).is_ok());
+        assert!(matches!(
+            GroupPermissionPolicy::ensure_can_manage_member(Admin, Admin, None),
+            Err(AppError::Forbidden(MessageKey::CannotManageEqualOrHigherRole)),
+        ));
+        assert!(matches!(
+            GroupPermissionPolicy::ensure_can_manage_member(Admin, Editor, Some(Admin)),
+            Err(AppError::Forbidden(MessageKey::CannotManageEqualOrHigherRole)),
+        ));
+    }
+
+    #[test]
+    fn owner_role_changes_only_by_transfer() {
+        use GroupRole::{Admin, Editor, Owner};
+
+        assert!(GroupPermissionPolicy::ensure_can_manage_member(Owner, Admin, Some(Editor)).is_ok());
+        assert!(matches!(
+            GroupPermissionPolicy::ensure_can_manage_member(Owner, Admin, Some(Owner)),
+            Err(AppError::Forbidden(MessageKey::OwnerRoleTransferOnly)),
+        ));
+        assert!(matches!(
+            GroupPermissionPolicy::ensure_can_manage_member(Admin, Owner, None),
+            Err(AppError::Forbidden(MessageKey::OwnerRoleTransferOnly)),
+        ));
+    }
+
+    #[test]
+    fn members_without_manage_permission_cannot_manage() {
+        assert!(matches!(
+            GroupPermissionPolicy::ensure_can_manage_member(GroupRole::Editor, GroupRole::Viewer, None),
+            Err(AppError::Forbidden(MessageKey::PermissionDenied)),
+        ));
+    }
+}
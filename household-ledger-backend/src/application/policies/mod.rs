@@ -0,0 +1,5 @@
+pub mod email_verification;
+pub mod group_permission;
+
+pub use email_verification::{EmailVerificationPolicy, RestrictedAction};
+pub use group_permission::{GroupPermission, GroupPermissionPolicy};
@@ -1,19 +1,48 @@
-use crate::domain::models::User;
-use crate::domain::repositories::UserRepository;
+use crate::domain::models::{DeviceInfo, Session, TokenPurpose, User, UserToken};
+use crate::domain::repositories::{SessionRepository, UserRepository, UserTokenRepository};
 use crate::errors::AppError;
-use crate::infrastructure::security::{hash_password, verify_password, JwtService};
+use crate::i18n::{Locale, MessageKey};
+use crate::utils::request_context::current_locale;
+use crate::infrastructure::mail::{EmailMessage, Mailer};
+use crate::infrastructure::security::jwt::REFRESH_TOKEN_TTL_DAYS;
+use crate::infrastructure::security::{generate_token, hash_token, JwtService, PasswordService};
 use std::sync::Arc;
+use uuid::Uuid;
+
+/// 비밀번호 재설정 토큰 유효 시간
+pub const PASSWORD_RESET_TTL_MINUTES: i64 = 60;
+/// 이메일 인증 토큰 유효 시간
+pub const EMAIL_VERIFICATION_TTL_MINUTES: i64 = 24 * 60;
 
 pub struct AuthService {
     user_repo: Box<dyn UserRepository>,
+    session_repo: Box<dyn SessionRepository>,
+    user_token_repo: Box<dyn UserTokenRepository>,
     jwt_service: Arc<JwtService>,
+    password_service: Arc<PasswordService>,
+    mailer: Arc<dyn Mailer>,
+    /// 메일에 넣을 링크의 기준 URL (예: https://app.example.com)
+    app_base_url: String,
 }
 
 impl AuthService {
-    pub fn new(user_repo: Box<dyn UserRepository>, jwt_service: Arc<JwtService>) -> Self {
+    pub fn new(
+        user_repo: Box<dyn UserRepository>,
+        session_repo: Box<dyn SessionRepository>,
+        user_token_repo: Box<dyn UserTokenRepository>,
+        jwt_service: Arc<JwtService>,
+        password_service: Arc<PasswordService>,
+        mailer: Arc<dyn Mailer>,
+        app_base_url: String,
+    ) -> Self {
         Self {
             user_repo,
+            session_repo,
+            user_token_repo,
             jwt_service,
+            password_service,
+            mailer,
+            app_base_url,
         }
     }
 
@@ -23,20 +52,22 @@ impl AuthService {
         password: String,
         nickname: String,
         invite_code: Option<String>,
+        device: DeviceInfo,
     ) -> Result<(User, String, String), AppError> {
         // 이메일 중복 체크
         if self.user_repo.find_by_email(&email).await?.is_some() {
-            return Err(AppError::Validation("이미 사용 중인 이메일입니다".to_string()));
+            return Err(AppError::Conflict(MessageKey::EmailAlreadyInUse));
         }
 
         // 비밀번호 해싱
-        let password_hash = hash_password(&password)?;
+        let password_hash = self.password_service.hash_password(&password)?;
 
         // 사용자 생성
         let user = User {
             id: 0,
             email,
             password_hash,
+            email_verified_at: None,
             nickname,
             avatar_url: None,
             group_id: None,
@@ -45,46 +76,360 @@ impl AuthService {
             created_at: chrono::Utc::now().naive_utc(),
         };
 
-        let created_user = self.user_repo.create(&user).await?;
+        // 초대 코드가 있으면 가입과 그룹 참여를 한 트랜잭션으로 처리.
+        // 초대받은 사용자이므로 미인증 계정의 그룹 참여 제한은 적용하지 않음
+        let invite_code = invite_code
+            .map(|code| code.trim().to_uppercase())
+            .filter(|code| !code.is_empty());
+        let created_user = match invite_code {
+            Some(code) => self.user_repo.create_with_invite(&user, &code).await?,
+            None => self.user_repo.create(&user).await?,
+        };
 
-        // TODO: invite_code 처리 (그룹 참여)
+        // 인증 메일 발송 실패로 가입이 실패하지 않도록 로그만 남김 (재발송 가능)
+        if let Err(e) = self.send_verification_email(&created_user).await {
+            tracing::error!(user_id = created_user.id, "이메일 인증 메일 발송 실패: {}", e);
+        }
 
-        // 토큰 생성
-        let access_token = self.jwt_service.generate_access_token(created_user.id, &created_user.email)?;
-        let refresh_token = self.jwt_service.generate_refresh_token(created_user.id, &created_user.email)?;
+        // 세션 및 토큰 생성
+        let (access_token, refresh_token) = self.start_session(&created_user, device).await?;
 
         Ok((created_user, access_token, refresh_token))
     }
 
-    pub async fn login(&self, email: String, password: String) -> Result<(User, String, String), AppError> {
+    pub async fn login(
+        &self,
+        email: String,
+        password: String,
+        device: DeviceInfo,
+    ) -> Result<(User, String, String), AppError> {
         let user = self.user_repo
             .find_by_email(&email)
             .await?
-            .ok_or_else(|| AppError::Authentication("이메일 또는 비밀번호가 올바르지 않습니다".to_string()))?;
+            .ok_or_else(|| AppError::Authentication(MessageKey::InvalidCredentials))?;
 
         // 비밀번호 검증
-        if !verify_password(&password, &user.password_hash)? {
-            return Err(AppError::Authentication("이메일 또는 비밀번호가 올바르지 않습니다".to_string()));
+        if !self.password_service.verify_password(&password, &user.password_hash)? {
+            return Err(AppError::Authentication(MessageKey::InvalidCredentials));
         }
 
-        // 토큰 생성
-        let access_token = self.jwt_service.generate_access_token(user.id, &user.email)?;
-        let refresh_token = self.jwt_service.generate_refresh_token(user.id, &user.email)?;
+        // 기존 BCrypt 해시 등은 로그인 성공 시 현재 설정의 Argon2id로 교체
+        let user = if self.password_service.needs_rehash(&user.password_hash) {
+            self.rehash_password(user, &password).await
+        } else {
+            user
+        };
+
+        // 세션 및 토큰 생성
+        let (access_token, refresh_token) = self.start_session(&user, device).await?;
 
         Ok((user, access_token, refresh_token))
     }
 
-    pub async fn refresh_token(&self, refresh_token: String) -> Result<String, AppError> {
-        let claims = self.jwt_service.verify_token(&refresh_token)?;
-        
+    pub async fn current_user(&self, user_id: i64) -> Result<User, AppError> {
+        self.user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::Authentication(MessageKey::UserNotFound))
+    }
+
+    /// refresh token을 회전시켜 새 access/refresh token 쌍을 발급합니다.
+    ///
+    /// 이미 사용된 refresh token이 다시 제시되면 탈취로 간주하고 세션 전체를 폐기합니다.
+    pub async fn refresh_token(
+        &self,
+        refresh_token: String,
+        device: DeviceInfo,
+    ) -> Result<(String, String), AppError> {
+        let claims = self.jwt_service.verify_refresh_token(&refresh_token)?;
+
+        let session = self.session_repo
+            .find_by_id(&claims.sid)
+            .await?
+            .filter(|session| session.user_id == claims.user_id)
+            .ok_or_else(|| AppError::Authentication(MessageKey::SessionNotFound))?;
+
+        if !session.is_active(chrono::Utc::now().naive_utc()) {
+            return Err(AppError::Authentication(MessageKey::SessionExpired));
+        }
+
+        let new_jti = Uuid::new_v4().to_string();
+        let rotated = self.session_repo
+            .rotate(&session.id, &claims.jti, &new_jti, Self::refresh_expires_at(), &device)
+            .await?;
+
+        if !rotated {
+            // 이전에 사용된 토큰의 재사용: 세션 family 전체 폐기
+            tracing::warn!(
+                user_id = claims.user_id,
+                session_id = %session.id,
+                "refresh token 재사용이 감지되어 세션을 폐기합니다"
+            );
+            self.session_repo.revoke(&session.id).await?;
+            return Err(AppError::Authentication(MessageKey::RefreshTokenReused));
+        }
+
         // 사용자 확인
-        let user = self.user_repo
-            .find_by_id(claims.user_id)
+        let user = self.current_user(claims.user_id).await?;
+
+        let access_token = self.jwt_service.generate_access_token(user.id, &user.email, &session.id)?;
+        let refresh_token = self.jwt_service.generate_refresh_token(user.id, &user.email, &session.id, &new_jti)?;
+
+        Ok((access_token, refresh_token))
+    }
+
+    /// access token의 세션이 아직 유효한지 확인합니다 (로그아웃/원격 로그아웃 반영).
+    pub async fn ensure_session_active(&self, user_id: i64, session_id: &str) -> Result<(), AppError> {
+        let active = self.session_repo
+            .find_by_id(session_id)
+            .await?
+            .map(|session| session.user_id == user_id && session.is_active(chrono::Utc::now().naive_utc()))
+            .unwrap_or(false);
+
+        if !active {
+            return Err(AppError::Authentication(MessageKey::SessionExpired));
+        }
+
+        Ok(())
+    }
+
+    pub async fn logout(&self, user_id: i64, session_id: &str) -> Result<(), AppError> {
+        self.revoke_session(user_id, session_id).await
+    }
+
+    pub async fn list_sessions(&self, user_id: i64) -> Result<Vec<Session>, AppError> {
+        self.session_repo.find_active_by_user(user_id).await
+    }
+
+    pub async fn revoke_session(&self, user_id: i64, session_id: &str) -> Result<(), AppError> {
+        // 다른 사용자의 세션은 존재하지 않는 것으로 취급
+        self.session_repo
+            .find_by_id(session_id)
+            .await?
+            .filter(|session| session.user_id == user_id)
+            .ok_or_else(|| AppError::NotFound(MessageKey::SessionNotFound))?;
+
+        self.session_repo.revoke(session_id).await
+    }
+
+    /// 현재 세션을 제외한 사용자의 모든 세션을 폐기하고, 폐기한 세션 수를 반환합니다.
+    pub async fn revoke_other_sessions(&self, user_id: i64, current_session_id: &str) -> Result<u64, AppError> {
+        self.session_repo
+            .revoke_all_for_user(user_id, Some(current_session_id))
+            .await
+    }
+
+    /// 현재 비밀번호를 확인한 뒤 변경하고, 현재 세션을 제외한 모든 세션을 폐기합니다.
+    pub async fn change_password(
+        &self,
+        user_id: i64,
+        current_session_id: &str,
+        current_password: &str,
+        new_password: &str,
+    ) -> Result<(), AppError> {
+        let mut user = self.current_user(user_id).await?;
+
+        if !self.password_service.verify_password(current_password, &user.password_hash)? {
+            return Err(AppError::Authentication(MessageKey::IncorrectCurrentPassword));
+        }
+
+        user.password_hash = self.password_service.hash_password(new_password)?;
+        self.user_repo.update(&user).await?;
+
+        self.session_repo
+            .revoke_all_for_user(user_id, Some(current_session_id))
+            .await?;
+        // 변경 전에 요청된 재설정 링크도 더 이상 쓸 수 없게 함
+        self.user_token_repo
+            .invalidate_for_user(user_id, TokenPurpose::PasswordReset)
+            .await?;
+
+        Ok(())
+    }
+
+    /// 비밀번호 재설정 메일을 보냅니다.
+    ///
+    /// 가입 여부가 드러나지 않도록 존재하지 않는 이메일이어도 성공으로 처리합니다.
+    pub async fn forgot_password(&self, email: &str) -> Result<(), AppError> {
+        let Some(user) = self.user_repo.find_by_email(email).await? else {
+            return Ok(());
+        };
+
+        let token = self
+            .issue_token(user.id, TokenPurpose::PasswordReset, PASSWORD_RESET_TTL_MINUTES)
+            .await?;
+
+        let locale = mail_locale(&user);
+        let link = format!("{}/reset-password?token={}", self.app_base_url.trim_end_matches('/'), token);
+        let message = EmailMessage {
+            to: user.email.clone(),
+            subject: MessageKey::PasswordResetMailSubject.text(locale).to_string(),
+            body: MessageKey::PasswordResetMailBody.render(locale, &[
+                ("nickname", &user.nickname),
+                ("link", &link),
+                ("minutes", &PASSWORD_RESET_TTL_MINUTES.to_string()),
+            ]),
+        };
+
+        // 발송 실패를 응답에 드러내면 가입 여부가 노출되므로 로그만 남김
+        if let Err(e) = self.mailer.send(&message).await {
+            tracing::error!(user_id = user.id, "비밀번호 재설정 메일 발송 실패: {}", e);
+        }
+
+        Ok(())
+    }
+
+    /// 재설정 토큰으로 비밀번호를 변경하고 모든 세션을 폐기합니다.
+    pub async fn reset_password(&self, token: &str, new_password: &str) -> Result<(), AppError> {
+        let invalid = || AppError::Validation(MessageKey::InvalidResetLink);
+
+        let reset_token = self.user_token_repo
+            .find_valid_by_hash(TokenPurpose::PasswordReset, &hash_token(token))
             .await?
-            .ok_or_else(|| AppError::Authentication("사용자를 찾을 수 없습니다".to_string()))?;
+            .ok_or_else(invalid)?;
+
+        // 동시에 같은 토큰이 제출되면 하나만 통과
+        if !self.user_token_repo.mark_used(reset_token.id).await? {
+            return Err(invalid());
+        }
+
+        let mut user = self.current_user(reset_token.user_id).await?;
+        user.password_hash = self.password_service.hash_password(new_password)?;
+        self.user_repo.update(&user).await?;
+
+        self.session_repo.revoke_all_for_user(user.id, None).await?;
+
+        Ok(())
+    }
+
+    /// 메일로 받은 토큰으로 이메일 인증을 완료합니다.
+    pub async fn verify_email(&self, token: &str) -> Result<User, AppError> {
+        let invalid = || AppError::Validation(MessageKey::InvalidVerificationLink);
+
+        let verification = self.user_token_repo
+            .find_valid_by_hash(TokenPurpose::EmailVerification, &hash_token(token))
+            .await?
+            .ok_or_else(invalid)?;
+
+        if !self.user_token_repo.mark_used(verification.id).await? {
+            return Err(invalid());
+        }
+
+        self.user_repo.mark_email_verified(verification.user_id).await?;
+
+        self.current_user(verification.user_id).await
+    }
+
+    /// 인증 메일을 다시 보냅니다. 이전에 보낸 링크는 무효가 됩니다.
+    pub async fn resend_verification_email(&self, user_id: i64) -> Result<(), AppError> {
+        let user = self.current_user(user_id).await?;
 
-        // 새 access token 생성
-        self.jwt_service.generate_access_token(user.id, &user.email)
+        if user.is_email_verified() {
+            return Err(AppError::Conflict(MessageKey::EmailAlreadyVerified));
+        }
+
+        self.send_verification_email(&user).await
+    }
+
+    async fn send_verification_email(&self, user: &User) -> Result<(), AppError> {
+        let token = self
+            .issue_token(user.id, TokenPurpose::EmailVerification, EMAIL_VERIFICATION_TTL_MINUTES)
+            .await?;
+
+        let locale = mail_locale(user);
+        let link = format!("{}/verify-email?token={}", self.app_base_url.trim_end_matches('/'), token);
+        let message = EmailMessage {
+            to: user.email.clone(),
+            subject: MessageKey::VerificationMailSubject.text(locale).to_string(),
+            body: MessageKey::VerificationMailBody.render(locale, &[
+                ("nickname", &user.nickname),
+                ("link", &link),
+                ("hours", &(EMAIL_VERIFICATION_TTL_MINUTES / 60).to_string()),
+            ]),
+        };
+
+        self.mailer.send(&message).await
+    }
+
+    /// 일회용 토큰을 발급하고 원문을 반환합니다. 같은 용도의 이전 토큰은 무효화됩니다.
+    async fn issue_token(
+        &self,
+        user_id: i64,
+        purpose: TokenPurpose,
+        ttl_minutes: i64,
+    ) -> Result<String, AppError> {
+        self.user_token_repo.invalidate_for_user(user_id, purpose).await?;
+
+        let token = generate_token();
+        let now = chrono::Utc::now();
+        self.user_token_repo
+            .create(&UserToken {
+                id: 0,
+                user_id,
+                purpose,
+                token_hash: hash_token(&token),
+                expires_at: (now + chrono::Duration::minutes(ttl_minutes)).naive_utc(),
+                used_at: None,
+                created_at: now.naive_utc(),
+            })
+            .await?;
+
+        Ok(token)
+    }
+
+    /// 비밀번호를 다시 해싱해 저장합니다. 실패해도 로그인은 계속 진행합니다.
+    async fn rehash_password(&self, mut user: User, password: &str) -> User {
+        let rehashed = match self.password_service.hash_password(password) {
+            Ok(hash) => hash,
+            Err(e) => {
+                tracing::warn!(user_id = user.id, "비밀번호 재해싱 실패: {}", e);
+                return user;
+            }
+        };
+
+        let previous_hash = std::mem::replace(&mut user.password_hash, rehashed);
+        match self.user_repo.update(&user).await {
+            Ok(updated) => updated,
+            Err(e) => {
+                tracing::warn!(user_id = user.id, "재해싱한 비밀번호 저장 실패: {}", e);
+                user.password_hash = previous_hash;
+                user
+            }
+        }
+    }
+
+    async fn start_session(&self, user: &User, device: DeviceInfo) -> Result<(String, String), AppError> {
+        let now = chrono::Utc::now().naive_utc();
+        let session = Session {
+            id: Uuid::new_v4().to_string(),
+            user_id: user.id,
+            refresh_jti: Uuid::new_v4().to_string(),
+            user_agent: device.user_agent,
+            ip_address: device.ip_address,
+            created_at: now,
+            last_used_at: now,
+            expires_at: Self::refresh_expires_at(),
+            revoked_at: None,
+        };
+        let session = self.session_repo.create(&session).await?;
+
+        let access_token = self.jwt_service.generate_access_token(user.id, &user.email, &session.id)?;
+        let refresh_token = self.jwt_service.generate_refresh_token(
+            user.id,
+            &user.email,
+            &session.id,
+            &session.refresh_jti,
+        )?;
+
+        Ok((access_token, refresh_token))
+    }
+
+    fn refresh_expires_at() -> chrono::NaiveDateTime {
+        (chrono::Utc::now() + chrono::Duration::days(REFRESH_TOKEN_TTL_DAYS)).naive_utc()
     }
 }
 
+/// 메일 언어: 사용자 설정을 우선하고, 없으면 요청 언어를 사용
+fn mail_locale(user: &User) -> Locale {
+    user.preferred_locale().unwrap_or_else(current_locale)
+}
@@ -1,46 +1,590 @@
-use crate::domain::models::{Category, TransactionType};
-use crate::domain::repositories::CategoryRepository;
+use std::collections::{HashMap, HashSet};
+use crate::application::policies::{GroupPermission, GroupPermissionPolicy};
+use crate::domain::models::{
+    Category, CategoryMergeSummary, CategoryNode, DefaultCategory, DefaultCategoryResult,
+    TransactionType, DEFAULT_CATEGORIES, DEFAULT_CATEGORY_VERSION,
+};
+use crate::domain::repositories::{CategoryRepository, GroupMemberRepository};
 use crate::errors::AppError;
+use crate::i18n::MessageKey;
+use crate::utils::request_context::current_locale;
+
+/// 카테고리 최대 단계 수 (상위 > 하위)
+pub const MAX_CATEGORY_DEPTH: usize = 2;
 
 pub struct CategoryService {
     category_repo: Box<dyn CategoryRepository>,
+    member_repo: Box<dyn GroupMemberRepository>,
 }
 
 impl CategoryService {
-    pub fn new(category_repo: Box<dyn CategoryRepository>) -> Self {
-        Self { category_repo }
+    pub fn new(
+        category_repo: Box<dyn CategoryRepository>,
+        member_repo: Box<dyn GroupMemberRepository>,
+    ) -> Self {
+        Self {
+            category_repo,
+            member_repo,
+        }
     }
 
-    pub async fn create_category(&self, category: Category) -> Result<Category, AppError> {
-        self.category_repo.create(&category).await
+    pub async fn create_category(&self, user_id: i64, mut category: Category) -> Result<Category, AppError> {
+        category.name = normalize_name(&category.name)?;
+
+        if let Some(group_id) = category.group_id {
+            GroupPermissionPolicy::authorize(
+                self.member_repo.as_ref(),
+                group_id,
+                user_id,
+                GroupPermission::ManageCategories,
+            )
+            .await?;
+            // 그룹 카테고리 예산은 예산 관리 권한도 필요
+            if category.budget_amount.is_some() {
+                GroupPermissionPolicy::authorize(
+                    self.member_repo.as_ref(),
+                    group_id,
+                    user_id,
+                    GroupPermission::ManageBudgets,
+                )
+                .await?;
+            }
+        }
+        self.ensure_valid_parent(&category).await?;
+
+        self.category_repo.create(&category).await.map_err(name_taken)
     }
 
-    pub async fn get_category(&self, id: i64) -> Result<Category, AppError> {
-        self.category_repo
+    pub async fn get_category(&self, user_id: i64, id: i64) -> Result<Category, AppError> {
+        let category = self.category_repo
             .find_by_id(id)
             .await?
-            .ok_or_else(|| AppError::NotFound(format!("Category with id {} not found", id)))
+            .ok_or_else(|| AppError::NotFound(MessageKey::CategoryNotFound))?;
+
+        // 접근 권한이 없는 카테고리는 존재 여부도 노출하지 않음
+        let accessible = match category.group_id {
+            Some(group_id) => self.member_repo.is_member(group_id, user_id).await?,
+            None => category.created_by == user_id,
+        };
+        if !accessible {
+            return Err(AppError::NotFound(MessageKey::CategoryNotFound));
+        }
+
+        Ok(category)
     }
 
+    /// `group_id`가 주어지면 그룹 카테고리를, 없으면 본인의 개인 카테고리를 이름순으로 조회합니다.
     pub async fn list_categories(
         &self,
+        user_id: i64,
         group_id: Option<i64>,
         transaction_type: Option<TransactionType>,
     ) -> Result<Vec<Category>, AppError> {
-        self.category_repo.find_by_group(group_id, transaction_type).await
+        match group_id {
+            Some(group_id) => {
+                GroupPermissionPolicy::authorize(
+                    self.member_repo.as_ref(),
+                    group_id,
+                    user_id,
+                    GroupPermission::ViewGroup,
+                )
+                .await?;
+                self.category_repo.find_by_group(group_id, transaction_type).await
+            }
+            None => self.category_repo.find_personal(user_id, transaction_type).await,
+        }
     }
 
-    pub async fn update_category(&self, category: Category) -> Result<Category, AppError> {
-        self.category_repo.update(&category).await
+    /// [`Self::list_categories`]와 같은 범위의 카테고리를 상위 > 하위 트리로 조회합니다.
+    pub async fn list_category_tree(
+        &self,
+        user_id: i64,
+        group_id: Option<i64>,
+        transaction_type: Option<TransactionType>,
+    ) -> Result<Vec<CategoryNode>, AppError> {
+        let categories = self.list_categories(user_id, group_id, transaction_type).await?;
+
+        Ok(build_tree(categories))
     }
 
-    pub async fn delete_category(&self, id: i64) -> Result<(), AppError> {
-        // 거래가 있는지 확인
-        if self.category_repo.has_transactions(id).await? {
-            return Err(AppError::Validation("거래가 연결된 카테고리는 삭제할 수 없습니다".to_string()));
+    /// 카테고리를 수정합니다. 그룹, 작성자, 유형, 기본 카테고리 여부는 바꿀 수 없습니다.
+    pub async fn update_category(&self, user_id: i64, mut category: Category) -> Result<Category, AppError> {
+        let existing = self.get_category(user_id, category.id).await?;
+        self.ensure_can_manage(user_id, &existing).await?;
+
+        // 예산 금액 변경은 예산 관리 권한도 필요
+        if let Some(group_id) = existing.group_id {
+            if existing.budget_amount != category.budget_amount {
+                GroupPermissionPolicy::authorize(
+                    self.member_repo.as_ref(),
+                    group_id,
+                    user_id,
+                    GroupPermission::ManageBudgets,
+                )
+                .await?;
+            }
+        }
+
+        category.name = normalize_name(&category.name)?;
+        category.group_id = existing.group_id;
+        category.created_by = existing.created_by;
+        category.transaction_type = existing.transaction_type;
+        category.is_default = existing.is_default;
+        if category.parent_id != existing.parent_id {
+            self.ensure_valid_parent(&category).await?;
+        }
+
+        self.category_repo.update(&category).await.map_err(name_taken)
+    }
+
+    /// 카테고리를 삭제합니다.
+    ///
+    /// `fallback_id`가 주어지면 거래, 반복 거래 규칙, 자동 분류 규칙, 하위 카테고리를 그 카테고리로 옮긴 뒤 삭제합니다
+    /// (예산은 옮기지 않음). 없으면 하위 카테고리나 거래가 연결된 카테고리는 삭제할 수 없으며, 연결된 거래 수를 오류로 알려줍니다.
+    pub async fn delete_category(
+        &self,
+        user_id: i64,
+        id: i64,
+        fallback_id: Option<i64>,
+    ) -> Result<(), AppError> {
+        let existing = self.get_category(user_id, id).await?;
+        self.ensure_can_manage(user_id, &existing).await?;
+
+        if let Some(fallback_id) = fallback_id {
+            self.merge_into(user_id, existing, fallback_id, false).await?;
+            return Ok(());
+        }
+
+        let siblings = self.scope_categories(&existing).await?;
+        if siblings.iter().any(|category| category.parent_id == Some(id)) {
+            return Err(AppError::Conflict(MessageKey::CategoryHasChildren));
+        }
+
+        let transaction_count = self.category_repo.count_transactions(id).await?;
+        if transaction_count > 0 {
+            return Err(AppError::InUse {
+                key: MessageKey::CategoryInUse,
+                transaction_count,
+            });
         }
 
         self.category_repo.delete(id).await
     }
+
+    /// `source_id` 카테고리를 `target_id` 카테고리에 합칩니다.
+    ///
+    /// 거래, 분할 항목, 반복 거래 규칙, 자동 분류 규칙, 하위 카테고리를 한 트랜잭션으로 옮기고
+    /// 원본 카테고리를 삭제합니다. 예산은 두 카테고리 예산의 합으로 바뀝니다.
+    pub async fn merge_categories(
+        &self,
+        user_id: i64,
+        source_id: i64,
+        target_id: i64,
+    ) -> Result<(Category, CategoryMergeSummary), AppError> {
+        let source = self.get_category(user_id, source_id).await?;
+        self.ensure_can_manage(user_id, &source).await?;
+
+        self.merge_into(user_id, source, target_id, true).await
+    }
+
+    /// 새 사용자(개인 범위) 또는 새 그룹에 요청 언어로 기본 카테고리를 만듭니다.
+    ///
+    /// 이미 기본 카테고리를 적용한 범위면 아무것도 만들지 않습니다. 실패하더라도 나중에
+    /// [`Self::sync_default_categories`]로 다시 받을 수 있으므로 가입과 그룹 생성은 실패를 기록만 하고 계속합니다.
+    pub async fn seed_default_categories(
+        &self,
+        user_id: i64,
+        group_id: Option<i64>,
+    ) -> Result<DefaultCategoryResult, AppError> {
+        self.authorize_defaults(user_id, group_id).await?;
+
+        let applied = self.category_repo.find_default_version(group_id, user_id).await?;
+        if let Some((version, _)) = applied {
+            return Ok(DefaultCategoryResult { version, ..Default::default() });
+        }
+
+        self.category_repo
+            .apply_defaults(
+                group_id,
+                user_id,
+                DEFAULT_CATEGORIES,
+                DEFAULT_CATEGORY_VERSION,
+                current_locale(),
+                false,
+            )
+            .await
+    }
+
+    /// 적용한 버전 이후에 추가된 기본 카테고리를 처음 적용한 언어로 만듭니다.
+    ///
+    /// 기본 카테고리를 적용한 적이 없는 범위(기능 도입 전 가입한 사용자 등)는 전체를 요청 언어로 만듭니다.
+    pub async fn sync_default_categories(
+        &self,
+        user_id: i64,
+        group_id: Option<i64>,
+    ) -> Result<DefaultCategoryResult, AppError> {
+        self.authorize_defaults(user_id, group_id).await?;
+
+        let (applied, locale) = self.category_repo
+            .find_default_version(group_id, user_id)
+            .await?
+            .unwrap_or((0, current_locale()));
+        if applied >= DEFAULT_CATEGORY_VERSION {
+            return Ok(DefaultCategoryResult { version: applied, ..Default::default() });
+        }
+
+        let added: Vec<DefaultCategory> = DEFAULT_CATEGORIES
+            .iter()
+            .filter(|default| default.since > applied)
+            .copied()
+            .collect();
+
+        self.category_repo
+            .apply_defaults(group_id, user_id, &added, DEFAULT_CATEGORY_VERSION, locale, false)
+            .await
+    }
+
+    /// 기본 카테고리를 요청 언어로 다시 적용합니다.
+    ///
+    /// 삭제한 기본 카테고리는 다시 만들고 남아 있는 기본 카테고리의 이름, 색상, 상위 카테고리는 기본값으로 되돌립니다.
+    /// 직접 만든 카테고리와 거래, 예산은 그대로 둡니다.
+    pub async fn reset_default_categories(
+        &self,
+        user_id: i64,
+        group_id: Option<i64>,
+    ) -> Result<DefaultCategoryResult, AppError> {
+        self.authorize_defaults(user_id, group_id).await?;
+
+        self.category_repo
+            .apply_defaults(
+                group_id,
+                user_id,
+                DEFAULT_CATEGORIES,
+                DEFAULT_CATEGORY_VERSION,
+                current_locale(),
+                true,
+            )
+            .await
+    }
+
+    /// 그룹 기본 카테고리는 카테고리 관리 권한이 필요하고, 개인 기본 카테고리는 본인 것만 다룹니다.
+    async fn authorize_defaults(&self, user_id: i64, group_id: Option<i64>) -> Result<(), AppError> {
+        match group_id {
+            Some(group_id) => {
+                GroupPermissionPolicy::authorize(
+                    self.member_repo.as_ref(),
+                    group_id,
+                    user_id,
+                    GroupPermission::ManageCategories,
+                )
+                .await?;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// 같은 범위, 같은 유형의 카테고리끼리만 합칠 수 있으며 합친 뒤에도 단계 제한을 지켜야 합니다.
+    async fn merge_into(
+        &self,
+        user_id: i64,
+        source: Category,
+        target_id: i64,
+        combine_budget: bool,
+    ) -> Result<(Category, CategoryMergeSummary), AppError> {
+        if source.id == target_id {
+            return Err(AppError::Validation(MessageKey::CategoryMergeIntoSelf));
+        }
+
+        let target = self.get_category(user_id, target_id).await?;
+        self.ensure_can_manage(user_id, &target).await?;
+
+        // 그룹 카테고리 예산을 합치려면 예산 관리 권한도 필요
+        if combine_budget && source.budget_amount.is_some() {
+            if let Some(group_id) = target.group_id {
+                GroupPermissionPolicy::authorize(
+                    self.member_repo.as_ref(),
+                    group_id,
+                    user_id,
+                    GroupPermission::ManageBudgets,
+                )
+                .await?;
+            }
+        }
+
+        // 범위, 유형, 단계 제한과 합친 예산은 저장소가 두 카테고리를 잠근 뒤 확인하고 계산
+        let summary = self.category_repo.merge(source.id, target.id, combine_budget).await?;
+        let merged = self.get_category(user_id, target.id).await?;
+
+        Ok((merged, summary))
+    }
+
+    /// 상위 카테고리는 같은 범위(그룹 또는 작성자의 개인 카테고리)의 같은 유형이어야 하며,
+    /// 옮긴 뒤에도 하위 카테고리를 포함해 [`MAX_CATEGORY_DEPTH`]단계를 넘지 않아야 합니다.
+    async fn ensure_valid_parent(&self, category: &Category) -> Result<(), AppError> {
+        if category.parent_id.is_none() {
+            return Ok(());
+        }
+
+        // 같은 범위, 같은 유형의 카테고리만 조회되므로 여기에 없으면 상위 카테고리가 될 수 없음
+        let categories = self.scope_categories(category).await?;
+        validate_parent(category, &categories)
+    }
+
+    /// 카테고리와 같은 범위(그룹 또는 작성자의 개인 카테고리), 같은 유형의 카테고리
+    async fn scope_categories(&self, category: &Category) -> Result<Vec<Category>, AppError> {
+        let transaction_type = Some(category.transaction_type);
+        match category.group_id {
+            Some(group_id) => self.category_repo.find_by_group(group_id, transaction_type).await,
+            None => self.category_repo.find_personal(category.created_by, transaction_type).await,
+        }
+    }
+
+    /// 그룹 카테고리는 관리 권한이 있는 멤버만, 개인 카테고리는 만든 사용자만 관리할 수 있습니다.
+    async fn ensure_can_manage(&self, user_id: i64, category: &Category) -> Result<(), AppError> {
+        match category.group_id {
+            Some(group_id) => {
+                GroupPermissionPolicy::authorize(
+                    self.member_repo.as_ref(),
+                    group_id,
+                    user_id,
+                    GroupPermission::ManageCategories,
+                )
+                .await?;
+                Ok(())
+            }
+            None if category.created_by == user_id => Ok(()),
+            None => Err(AppError::NotFound(MessageKey::CategoryNotFound)),
+        }
+    }
+}
+
+/// [`CategoryService::ensure_valid_parent`]의 검사를 같은 범위, 같은 유형의 카테고리 목록으로 수행합니다.
+fn validate_parent(category: &Category, categories: &[Category]) -> Result<(), AppError> {
+    let Some(parent_id) = category.parent_id else {
+        return Ok(());
+    };
+
+    let by_id: HashMap<i64, &Category> = categories.iter().map(|c| (c.id, c)).collect();
+    let Some(mut ancestor) = by_id.get(&parent_id).copied() else {
+        return Err(AppError::Validation(MessageKey::CategoryParentInvalid));
+    };
+    if parent_id == category.id {
+        return Err(AppError::Validation(MessageKey::CategoryParentInvalid));
+    }
+
+    // 상위 카테고리의 단계 (최상위는 1), 자기 자신을 거치면 순환
+    let mut parent_depth = 1;
+    while let Some(next_id) = ancestor.parent_id {
+        if next_id == category.id {
+            return Err(AppError::Validation(MessageKey::CategoryParentInvalid));
+        }
+        if parent_depth >= MAX_CATEGORY_DEPTH {
+            return Err(AppError::Validation(MessageKey::CategoryDepthExceeded));
+        }
+        let Some(next) = by_id.get(&next_id).copied() else {
+            break;
+        };
+        ancestor = next;
+        parent_depth += 1;
+    }
+
+    if parent_depth + subtree_height(category.id, categories) > MAX_CATEGORY_DEPTH {
+        return Err(AppError::Validation(MessageKey::CategoryDepthExceeded));
+    }
+
+    Ok(())
 }
 
+/// 이름순으로 정렬된 카테고리를 트리로 묶습니다. 상위 카테고리가 목록에 없으면 최상위로 둡니다.
+fn build_tree(categories: Vec<Category>) -> Vec<CategoryNode> {
+    let ids: HashSet<i64> = categories.iter().map(|category| category.id).collect();
+    let mut children: HashMap<i64, Vec<Category>> = HashMap::new();
+    let mut roots = Vec::new();
+    for category in categories {
+        match category.parent_id {
+            Some(parent_id) if ids.contains(&parent_id) => children.entry(parent_id).or_default().push(category),
+            _ => roots.push(category),
+        }
+    }
+
+    roots
+        .into_iter()
+        .map(|category| attach_children(category, &mut children))
+        .collect()
+}
+
+fn attach_children(category: Category, children: &mut HashMap<i64, Vec<Category>>) -> CategoryNode {
+    let nodes = children
+        .remove(&category.id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|child| attach_children(child, children))
+        .collect();
+
+    CategoryNode {
+        category,
+        children: nodes,
+    }
+}
+
+/// `id`를 최상위로 하는 하위 트리의 단계 수 (하위 카테고리가 없으면 1)
+fn subtree_height(id: i64, categories: &[Category]) -> usize {
+    fn height(id: i64, categories: &[Category], depth: usize) -> usize {
+        // 저장된 데이터가 순환하더라도 끝나도록 최대 단계에서 멈춤
+        if depth > MAX_CATEGORY_DEPTH {
+            return depth;
+        }
+        categories
+            .iter()
+            .filter(|category| category.parent_id == Some(id))
+            .map(|child| height(child.id, categories, depth + 1))
+            .max()
+            .unwrap_or(depth)
+    }
+
+    height(id, categories, 1)
+}
+
+fn normalize_name(name: &str) -> Result<String, AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::Validation(MessageKey::CategoryNameRequired));
+    }
+
+    Ok(name.to_string())
+}
+
+/// 같은 범위에 이름과 유형이 같은 카테고리가 있으면 UNIQUE 제약 위반 대신 구체적인 메시지로 응답
+fn name_taken(error: AppError) -> AppError {
+    if error.is_unique_violation() {
+        AppError::Conflict(MessageKey::CategoryNameTaken)
+    } else {
+        error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use super::*;
+
+    fn category(id: i64, parent_id: Option<i64>, name: &str) -> Category {
+        Category {
+            id,
+            group_id: Some(1),
+            created_by: 1,
+            parent_id,
+            name: name.to_string(),
+            transaction_type: TransactionType::Expense,
+            color: None,
+            is_default: false,
+            default_key: None,
+            budget_amount: None,
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+        }
+    }
+
+    fn moved(mut category: Category, parent_id: Option<i64>) -> Category {
+        category.parent_id = parent_id;
+        category
+    }
+
+    #[test]
+    fn build_tree_nests_children_and_keeps_order() {
+        let tree = build_tree(vec![
+            category(1, None, "식비"),
+            category(3, Some(1), "외식"),
+            category(2, None, "주거"),
+            category(4, Some(1), "장보기"),
+        ]);
+
+        let roots: Vec<i64> = tree.iter().map(|node| node.category.id).collect();
+        let children: Vec<i64> = tree[0].children.iter().map(|node| node.category.id).collect();
+        assert_eq!(roots, vec![1, 2]);
+        assert_eq!(children, vec![3, 4]);
+        assert!(tree[1].children.is_empty());
+    }
+
+    #[test]
+    fn build_tree_promotes_orphans_to_roots() {
+        let tree = build_tree(vec![category(1, None, "식비"), category(2, Some(99), "외식")]);
+
+        let roots: Vec<i64> = tree.iter().map(|node| node.category.id).collect();
+        assert_eq!(roots, vec![1, 2]);
+    }
+
+    #[test]
+    fn subtree_height_counts_levels() {
+        let categories = [
+            category(1, None, "식비"),
+            category(2, Some(1), "외식"),
+            category(3, None, "주거"),
+        ];
+
+        assert_eq!(subtree_height(1, &categories), 2);
+        assert_eq!(subtree_height(3, &categories), 1);
+        assert_eq!(subtree_height(99, &categories), 1);
+    }
+
+    #[test]
+    fn subtree_height_stops_on_cycles() {
+        let categories = [category(1, Some(2), "식비"), category(2, Some(1), "외식")];
+
+        assert!(subtree_height(1, &categories) > MAX_CATEGORY_DEPTH);
+    }
+
+    #[test]
+    fn validate_parent_accepts_root_and_valid_parent() {
+        let categories = [category(1, None, "식비"), category(2, None, "외식")];
+
+        assert!(validate_parent(&categories[1], &categories).is_ok());
+        assert!(validate_parent(&moved(categories[1].clone(), Some(1)), &categories).is_ok());
+    }
+
+    #[test]
+    fn validate_parent_rejects_parent_outside_scope_or_self() {
+        let categories = [category(1, None, "식비")];
+
+        assert!(matches!(
+            validate_parent(&category(2, Some(99), "외식"), &categories),
+            Err(AppError::Validation(MessageKey::CategoryParentInvalid)),
+        ));
+        assert!(matches!(
+            validate_parent(&moved(categories[0].clone(), Some(1)), &categories),
+            Err(AppError::Validation(MessageKey::CategoryParentInvalid)),
+        ));
+    }
+
+    #[test]
+    fn validate_parent_rejects_cycles() {
+        let categories = [category(1, None, "식비"), category(2, Some(1), "외식")];
+
+        assert!(matches!(
+            validate_parent(&moved(categories[0].clone(), Some(2)), &categories),
+            Err(AppError::Validation(MessageKey::CategoryParentInvalid)),
+        ));
+    }
+
+    #[test]
+    fn validate_parent_rejects_too_deep_trees() {
+        let categories = [
+            category(1, None, "식비"),
+            category(2, Some(1), "외식"),
+            category(3, None, "주거"),
+            category(4, Some(3), "관리비"),
+        ];
+
+        // 하위 카테고리 아래로 옮길 수 없음
+        assert!(matches!(
+            validate_parent(&category(5, Some(2), "배달"), &categories),
+            Err(AppError::Validation(MessageKey::CategoryDepthExceeded)),
+        ));
+        // 하위 카테고리가 있는 카테고리는 다른 카테고리 아래로 옮길 수 없음
+        assert!(matches!(
+            validate_parent(&moved(categories[2].clone(), Some(1)), &categories),
+            Err(AppError::Validation(MessageKey::CategoryDepthExceeded)),
+        ));
+    }
+}
@@ -1,25 +1,47 @@
-use crate::domain::models::Group;
-use crate::domain::repositories::{GroupRepository, UserRepository};
+use crate::application::policies::{
+    EmailVerificationPolicy, GroupPermission, GroupPermissionPolicy, RestrictedAction,
+};
+use crate::domain::models::{Group, GroupInvite, GroupMember, GroupRole, User};
+use crate::domain::repositories::{
+    GroupInviteRepository, GroupMemberRepository, GroupRepository, UserRepository,
+};
 use crate::errors::AppError;
+use crate::i18n::MessageKey;
+use std::sync::Arc;
 use uuid::Uuid;
 
+/// 초대 코드 기본 유효 시간 (PRD: 24시간)
+pub const DEFAULT_INVITE_TTL_HOURS: i64 = 24;
+
 pub struct GroupService {
     group_repo: Box<dyn GroupRepository>,
     user_repo: Box<dyn UserRepository>,
+    invite_repo: Box<dyn GroupInviteRepository>,
+    member_repo: Box<dyn GroupMemberRepository>,
+    verification_policy: Arc<EmailVerificationPolicy>,
 }
 
 impl GroupService {
     pub fn new(
         group_repo: Box<dyn GroupRepository>,
         user_repo: Box<dyn UserRepository>,
+        invite_repo: Box<dyn GroupInviteRepository>,
+        member_repo: Box<dyn GroupMemberRepository>,
+        verification_policy: Arc<EmailVerificationPolicy>,
     ) -> Self {
         Self {
             group_repo,
             user_repo,
+            invite_repo,
+            member_repo,
+            verification_policy,
         }
     }
 
     pub async fn create_group(&self, name: String, owner_id: i64) -> Result<Group, AppError> {
+        let mut user = self.find_user(owner_id).await?;
+        self.verification_policy.ensure_allowed(&user, RestrictedAction::CreateGroup)?;
+
         let group = Group {
             id: 0,
             name,
@@ -27,16 +49,14 @@ impl GroupService {
             created_at: chrono::Utc::now().naive_utc(),
         };
 
+        // 그룹 생성과 소유자 멤버십 등록
         let created_group = self.group_repo.create(&group).await?;
 
-        // 사용자의 group_id 업데이트
-        let mut user = self.user_repo
-            .find_by_id(owner_id)
-            .await?
-            .ok_or_else(|| AppError::NotFound(format!("User with id {} not found", owner_id)))?;
-        
-        user.group_id = Some(created_group.id);
-        self.user_repo.update(&user).await?;
+        // 기본 그룹이 없으면 새 그룹을 기본 그룹으로 설정
+        if user.group_id.is_none() {
+            user.group_id = Some(created_group.id);
+            self.user_repo.update(&user).await?;
+        }
 
         Ok(created_group)
     }
@@ -45,31 +65,303 @@ impl GroupService {
         self.group_repo
             .find_by_id(id)
             .await?
-            .ok_or_else(|| AppError::NotFound(format!("Group with id {} not found", id)))
+            .ok_or_else(|| AppError::NotFound(MessageKey::GroupNotFound))
+    }
+
+    /// 사용자가 속한 그룹 목록
+    pub async fn list_groups(&self, user_id: i64) -> Result<Vec<Group>, AppError> {
+        self.group_repo.find_by_member(user_id).await
+    }
+
+    /// 그룹 내 역할 (멤버가 아니면 None)
+    pub async fn member_role(&self, group_id: i64, user_id: i64) -> Result<Option<GroupRole>, AppError> {
+        self.member_repo.find_role(group_id, user_id).await
+    }
+
+    /// 그룹 상세와 멤버 수를 조회합니다.
+    pub async fn get_group_detail(&self, group_id: i64, user_id: i64) -> Result<(Group, i64), AppError> {
+        GroupPermissionPolicy::authorize(
+            self.member_repo.as_ref(),
+            group_id,
+            user_id,
+            GroupPermission::ViewGroup,
+        )
+        .await?;
+
+        let group = self.get_group(group_id).await?;
+        let member_count = self.group_repo.count_members(group_id).await?;
+
+        Ok((group, member_count))
+    }
+
+    /// 그룹 이름을 변경합니다.
+    pub async fn rename_group(&self, group_id: i64, user_id: i64, name: String) -> Result<Group, AppError> {
+        GroupPermissionPolicy::authorize(
+            self.member_repo.as_ref(),
+            group_id,
+            user_id,
+            GroupPermission::UpdateGroup,
+        )
+        .await?;
+
+        let mut group = self.get_group(group_id).await?;
+        group.name = name;
+        self.group_repo.update(&group).await
+    }
+
+    /// 그룹에서 탈퇴합니다. 소유자는 소유권을 넘긴 뒤에만 탈퇴할 수 있습니다.
+    pub async fn leave_group(&self, group_id: i64, user_id: i64) -> Result<(), AppError> {
+        let role = GroupPermissionPolicy::authorize(
+            self.member_repo.as_ref(),
+            group_id,
+            user_id,
+            GroupPermission::ViewGroup,
+        )
+        .await?;
+
+        if role == GroupRole::Owner {
+            return Err(AppError::Conflict(MessageKey::OwnerMustTransferBeforeLeaving));
+        }
+
+        self.member_repo.remove(group_id, user_id).await?;
+
+        let user = self.find_user(user_id).await?;
+        self.reset_default_group(user, group_id).await
+    }
+
+    /// 그룹 소유권을 다른 멤버에게 넘깁니다. 이전 소유자는 관리자가 됩니다.
+    pub async fn transfer_ownership(
+        &self,
+        group_id: i64,
+        user_id: i64,
+        new_owner_id: i64,
+    ) -> Result<Group, AppError> {
+        GroupPermissionPolicy::authorize(
+            self.member_repo.as_ref(),
+            group_id,
+            user_id,
+            GroupPermission::TransferOwnership,
+        )
+        .await?;
+
+        if new_owner_id == user_id {
+            return Err(AppError::Validation(MessageKey::AlreadyGroupOwner));
+        }
+
+        if !self.member_repo.is_member(group_id, new_owner_id).await? {
+            return Err(AppError::Validation(MessageKey::TransferToNonMember));
+        }
+
+        self.group_repo.transfer_ownership(group_id, user_id, new_owner_id).await
+    }
+
+    /// 멤버 목록과 각 멤버의 사용자 정보
+    pub async fn list_members(&self, group_id: i64, user_id: i64) -> Result<Vec<(GroupMember, User)>, AppError> {
+        GroupPermissionPolicy::authorize(
+            self.member_repo.as_ref(),
+            group_id,
+            user_id,
+            GroupPermission::ViewGroup,
+        )
+        .await?;
+
+        let members = self.member_repo.find_by_group(group_id).await?;
+        let mut users = self.user_repo.find_by_group_id(group_id).await?;
+
+        Ok(members
+            .into_iter()
+            .filter_map(|member| {
+                let index = users.iter().position(|user| user.id == member.user_id)?;
+                Some((member, users.swap_remove(index)))
+            })
+            .collect())
+    }
+
+    /// 멤버의 역할을 변경합니다. 소유자 역할은 소유권 이전으로만 바뀝니다.
+    pub async fn change_member_role(
+        &self,
+        group_id: i64,
+        user_id: i64,
+        member_id: i64,
+        role: GroupRole,
+    ) -> Result<(), AppError> {
+        let actor_role = GroupPermissionPolicy::authorize(
+            self.member_repo.as_ref(),
+            group_id,
+            user_id,
+            GroupPermission::ManageMembers,
+        )
+        .await?;
+        let target_role = self.find_member_role(group_id, member_id).await?;
+
+        GroupPermissionPolicy::ensure_can_manage_member(actor_role, target_role, Some(role))?;
+
+        self.member_repo.update_role(group_id, member_id, role).await
+    }
+
+    /// 멤버를 그룹에서 내보냅니다.
+    pub async fn remove_member(&self, group_id: i64, user_id: i64, member_id: i64) -> Result<(), AppError> {
+        if member_id == user_id {
+            return Err(AppError::Validation(MessageKey::UseLeaveForSelf));
+        }
+
+        let actor_role = GroupPermissionPolicy::authorize(
+            self.member_repo.as_ref(),
+            group_id,
+            user_id,
+            GroupPermission::ManageMembers,
+        )
+        .await?;
+        let target_role = self.find_member_role(group_id, member_id).await?;
+
+        GroupPermissionPolicy::ensure_can_manage_member(actor_role, target_role, None)?;
+
+        self.member_repo.remove(group_id, member_id).await?;
+
+        let member = self.find_user(member_id).await?;
+        self.reset_default_group(member, group_id).await
+    }
+
+    /// 초대 코드를 생성합니다.
+    pub async fn create_invite(
+        &self,
+        group_id: i64,
+        user_id: i64,
+        expires_in_hours: Option<i64>,
+        max_uses: Option<i32>,
+    ) -> Result<GroupInvite, AppError> {
+        let user = self.find_user(user_id).await?;
+        self.verification_policy.ensure_allowed(&user, RestrictedAction::CreateInvite)?;
+        GroupPermissionPolicy::authorize(
+            self.member_repo.as_ref(),
+            group_id,
+            user_id,
+            GroupPermission::ManageInvites,
+        )
+        .await?;
+
+        let now = chrono::Utc::now();
+        let expires_in = expires_in_hours.unwrap_or(DEFAULT_INVITE_TTL_HOURS);
+        let invite = GroupInvite {
+            id: 0,
+            group_id,
+            code: Self::generate_invite_code(),
+            created_by: user_id,
+            max_uses,
+            use_count: 0,
+            expires_at: (now + chrono::Duration::hours(expires_in)).naive_utc(),
+            created_at: now.naive_utc(),
+        };
+
+        self.invite_repo.create(&invite).await
+    }
+
+    /// 사용 가능한 초대 코드 목록
+    pub async fn list_invites(&self, group_id: i64, user_id: i64) -> Result<Vec<GroupInvite>, AppError> {
+        GroupPermissionPolicy::authorize(
+            self.member_repo.as_ref(),
+            group_id,
+            user_id,
+            GroupPermission::ManageInvites,
+        )
+        .await?;
+
+        self.invite_repo.find_active_by_group(group_id).await
+    }
+
+    /// 초대 코드를 폐기합니다.
+    pub async fn revoke_invite(&self, group_id: i64, invite_id: i64, user_id: i64) -> Result<(), AppError> {
+        GroupPermissionPolicy::authorize(
+            self.member_repo.as_ref(),
+            group_id,
+            user_id,
+            GroupPermission::ManageInvites,
+        )
+        .await?;
+
+        self.invite_repo
+            .find_by_id(invite_id)
+            .await?
+            .filter(|invite| invite.group_id == group_id)
+            .ok_or_else(|| AppError::NotFound(MessageKey::InviteNotFound))?;
+
+        self.invite_repo.delete(invite_id).await
     }
 
-    pub async fn generate_invite_code(&self, group_id: i64) -> Result<String, AppError> {
-        // 10자리 영문+숫자 코드 생성
-        let code = Uuid::new_v4()
+    /// 초대 코드로 그룹에 참여합니다.
+    pub async fn join_group(&self, user_id: i64, code: &str) -> Result<Group, AppError> {
+        let user = self.find_user(user_id).await?;
+        self.verification_policy.ensure_allowed(&user, RestrictedAction::JoinGroup)?;
+
+        let invite = self.invite_repo
+            .redeem(&code.trim().to_uppercase(), user_id)
+            .await?;
+
+        self.get_group(invite.group_id).await
+    }
+
+    /// 만료되었거나 소진된 초대 코드를 정리하고 삭제한 개수를 반환합니다.
+    pub async fn cleanup_expired_invites(&self) -> Result<u64, AppError> {
+        self.invite_repo.delete_expired().await
+    }
+
+    /// 그룹을 삭제합니다.
+    ///
+    /// 소유자 외의 멤버가 남아 있으면 삭제할 수 없습니다.
+    pub async fn delete_group(&self, id: i64, user_id: i64) -> Result<(), AppError> {
+        GroupPermissionPolicy::authorize(
+            self.member_repo.as_ref(),
+            id,
+            user_id,
+            GroupPermission::DeleteGroup,
+        )
+        .await?;
+
+        // 멤버 확인과 삭제는 저장소가 그룹을 잠근 채로 수행
+        self.group_repo.delete(id).await?;
+
+        let user = self.find_user(user_id).await?;
+        self.reset_default_group(user, id).await
+    }
+
+    /// 10자리 영문+숫자 코드 생성
+    fn generate_invite_code() -> String {
+        Uuid::new_v4()
             .to_string()
             .replace("-", "")
             .chars()
             .take(10)
             .collect::<String>()
-            .to_uppercase();
+            .to_uppercase()
+    }
 
-        // TODO: GroupInviteRepository를 사용하여 초대 코드 저장
+    /// 기본 그룹에서 빠진 경우 남은 그룹 중 가장 먼저 참여한 그룹을 기본 그룹으로 지정합니다.
+    async fn reset_default_group(&self, mut user: User, removed_group_id: i64) -> Result<(), AppError> {
+        if user.group_id != Some(removed_group_id) {
+            return Ok(());
+        }
 
-        Ok(code)
+        user.group_id = self.member_repo
+            .find_by_user(user.id)
+            .await?
+            .first()
+            .map(|membership| membership.group_id);
+        self.user_repo.update(&user).await?;
+
+        Ok(())
     }
 
-    pub async fn delete_group(&self, id: i64) -> Result<(), AppError> {
-        // 멤버가 있는지 확인
-        if self.group_repo.has_members(id).await? {
-            return Err(AppError::Validation("멤버가 있는 그룹은 삭제할 수 없습니다".to_string()));
-        }
+    async fn find_member_role(&self, group_id: i64, member_id: i64) -> Result<GroupRole, AppError> {
+        self.member_repo
+            .find_role(group_id, member_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::GroupMemberNotFound))
+    }
 
-        self.group_repo.delete(id).await
+    async fn find_user(&self, user_id: i64) -> Result<User, AppError> {
+        self.user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::UserNotFound))
     }
 }
-
@@ -1,9 +1,11 @@
 pub mod auth_service;
 pub mod category_service;
 pub mod group_service;
+pub mod tag_service;
 pub mod transaction_service;
 
 pub use auth_service::AuthService;
 pub use category_service::CategoryService;
 pub use group_service::GroupService;
-pub use transaction_service::TransactionService;
+pub use tag_service::TagService;
+pub use transaction_service::{BulkItemOutcome, TransactionService};
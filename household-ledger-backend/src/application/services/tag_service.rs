@@ -0,0 +1,154 @@
+use crate::application::policies::{GroupPermission, GroupPermissionPolicy};
+use crate::domain::models::Tag;
+use crate::domain::repositories::{GroupMemberRepository, TagRepository};
+use crate::errors::AppError;
+use crate::i18n::MessageKey;
+
+pub struct TagService {
+    tag_repo: Box<dyn TagRepository>,
+    member_repo: Box<dyn GroupMemberRepository>,
+}
+
+impl TagService {
+    pub fn new(
+        tag_repo: Box<dyn TagRepository>,
+        member_repo: Box<dyn GroupMemberRepository>,
+    ) -> Self {
+        Self {
+            tag_repo,
+            member_repo,
+        }
+    }
+
+    /// `group_id`가 주어지면 그룹 태그를, 없으면 본인의 개인 태그를 이름순으로 조회합니다.
+    pub async fn list_tags(&self, user_id: i64, group_id: Option<i64>) -> Result<Vec<Tag>, AppError> {
+        match group_id {
+            Some(group_id) => {
+                GroupPermissionPolicy::authorize(
+                    self.member_repo.as_ref(),
+                    group_id,
+                    user_id,
+                    GroupPermission::ViewGroup,
+                )
+                .await?;
+                self.tag_repo.find_by_group(group_id).await
+            }
+            None => self.tag_repo.find_personal(user_id).await,
+        }
+    }
+
+    /// 그룹 태그는 거래를 작성할 수 있는 멤버가 만들 수 있습니다.
+    pub async fn create_tag(&self, user_id: i64, mut tag: Tag) -> Result<Tag, AppError> {
+        tag.name = normalize_name(&tag.name)?;
+
+        if let Some(group_id) = tag.group_id {
+            GroupPermissionPolicy::authorize(
+                self.member_repo.as_ref(),
+                group_id,
+                user_id,
+                GroupPermission::CreateTransaction,
+            )
+            .await?;
+        }
+
+        self.tag_repo.create(&tag).await.map_err(name_taken)
+    }
+
+    pub async fn get_tag(&self, user_id: i64, id: i64) -> Result<Tag, AppError> {
+        let tag = self.tag_repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::TagNotFound))?;
+
+        // 접근 권한이 없는 태그는 존재 여부도 노출하지 않음
+        let accessible = match tag.group_id {
+            Some(group_id) => self.member_repo.is_member(group_id, user_id).await?,
+            None => tag.created_by == user_id,
+        };
+        if !accessible {
+            return Err(AppError::NotFound(MessageKey::TagNotFound));
+        }
+
+        Ok(tag)
+    }
+
+    /// 태그 이름을 바꿉니다. 거래는 태그 ID로 연결되므로 연결된 모든 거래에 바로 반영됩니다.
+    ///
+    /// 같은 범위에 이미 있는 이름으로는 바꿀 수 없으며, 이 경우 병합을 사용합니다.
+    pub async fn rename_tag(&self, user_id: i64, id: i64, name: &str) -> Result<Tag, AppError> {
+        let mut tag = self.get_tag(user_id, id).await?;
+        self.ensure_can_manage(user_id, &tag).await?;
+
+        tag.name = normalize_name(name)?;
+        self.tag_repo.update(&tag).await.map_err(name_taken)
+    }
+
+    /// `source_id` 태그가 붙은 거래를 모두 `target_id` 태그로 옮기고 `source_id` 태그를 삭제합니다.
+    ///
+    /// 병합 후 태그와 옮긴 거래 수를 반환합니다.
+    pub async fn merge_tags(
+        &self,
+        user_id: i64,
+        source_id: i64,
+        target_id: i64,
+    ) -> Result<(Tag, u64), AppError> {
+        let source = self.get_tag(user_id, source_id).await?;
+        let target = self.get_tag(user_id, target_id).await?;
+        if source.id == target.id {
+            return Err(AppError::Validation(MessageKey::TagMergeIntoSelf));
+        }
+
+        // 개인 태그는 같은 사용자의 것끼리만 (접근 확인으로 이미 본인 태그임)
+        if source.group_id != target.group_id {
+            return Err(AppError::Validation(MessageKey::TagMergeScopeMismatch));
+        }
+        self.ensure_can_manage(user_id, &source).await?;
+
+        let moved = self.tag_repo.merge(source.id, target.id).await?;
+        Ok((target, moved))
+    }
+
+    /// 태그를 삭제합니다. 거래에서는 태그만 떨어지고 거래는 유지됩니다.
+    pub async fn delete_tag(&self, user_id: i64, id: i64) -> Result<(), AppError> {
+        let tag = self.get_tag(user_id, id).await?;
+        self.ensure_can_manage(user_id, &tag).await?;
+
+        self.tag_repo.delete(id).await
+    }
+
+    /// 그룹 태그는 관리 권한이 있는 멤버만, 개인 태그는 만든 사용자만 관리할 수 있습니다.
+    async fn ensure_can_manage(&self, user_id: i64, tag: &Tag) -> Result<(), AppError> {
+        match tag.group_id {
+            Some(group_id) => {
+                GroupPermissionPolicy::authorize(
+                    self.member_repo.as_ref(),
+                    group_id,
+                    user_id,
+                    GroupPermission::ManageTags,
+                )
+                .await?;
+                Ok(())
+            }
+            None if tag.created_by == user_id => Ok(()),
+            None => Err(AppError::NotFound(MessageKey::TagNotFound)),
+        }
+    }
+}
+
+fn normalize_name(name: &str) -> Result<String, AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::Validation(MessageKey::TagNameRequired));
+    }
+
+    Ok(name.to_string())
+}
+
+/// 같은 범위에 같은 이름의 태그가 있으면 UNIQUE 제약 위반 대신 구체적인 메시지로 응답
+fn name_taken(error: AppError) -> AppError {
+    if error.is_unique_violation() {
+        AppError::Conflict(MessageKey::TagNameTaken)
+    } else {
+        error
+    }
+}
@@ -1,71 +1,731 @@
-use crate::domain::models::Transaction;
-use crate::domain::repositories::TransactionRepository;
+use std::collections::{HashMap, HashSet};
+use crate::application::policies::{GroupPermission, GroupPermissionPolicy};
+use crate::domain::models::{
+    BulkMode, BulkTransactionOp, BulkWrite, Category, CategoryTotal, QuickAddedTransaction, Tag,
+    Transaction, TransactionCursor, TransactionDetails, TransactionFilter, TransactionLinks,
+    TransactionPage, TransactionScope, TransactionSearchHit, TransactionSort, TransactionSplit,
+};
+use crate::domain::repositories::{
+    CategoryRepository, GroupMemberRepository, TagRepository, TransactionRepository,
+};
 use crate::errors::AppError;
+use crate::i18n::MessageKey;
+
+/// 일괄 처리 항목별 결과
+#[derive(Debug)]
+pub enum BulkItemOutcome {
+    /// 반영됨 (삭제한 경우 삭제 전 거래)
+    Succeeded(Transaction),
+    Failed(AppError),
+    /// 다른 항목이 실패해 반영하지 않음 (`AllOrNothing`)
+    Skipped,
+}
 
 pub struct TransactionService {
     transaction_repo: Box<dyn TransactionRepository>,
+    category_repo: Box<dyn CategoryRepository>,
+    tag_repo: Box<dyn TagRepository>,
+    member_repo: Box<dyn GroupMemberRepository>,
 }
 
 impl TransactionService {
-    pub fn new(transaction_repo: Box<dyn TransactionRepository>) -> Self {
-        Self { transaction_repo }
+    pub fn new(
+        transaction_repo: Box<dyn TransactionRepository>,
+        category_repo: Box<dyn CategoryRepository>,
+        tag_repo: Box<dyn TagRepository>,
+        member_repo: Box<dyn GroupMemberRepository>,
+    ) -> Self {
+        Self {
+            transaction_repo,
+            category_repo,
+            tag_repo,
+            member_repo,
+        }
     }
 
+    /// 거래를 만듭니다. `links`의 분할 항목과 태그도 함께 저장합니다.
     pub async fn create_transaction(
         &self,
+        user_id: i64,
         transaction: Transaction,
+        links: TransactionLinks,
     ) -> Result<Transaction, AppError> {
-        // 비즈니스 로직: 검증
-        if transaction.amount <= 0 {
-            return Err(AppError::Validation("거래 금액은 양수여야 합니다".to_string()));
+        self.ensure_can_create(user_id, &transaction, &links).await?;
+
+        self.transaction_repo.create(&transaction, &links).await
+    }
+
+    /// 카테고리 ID 대신 이름으로 거래를 만듭니다.
+    ///
+    /// 거래와 같은 범위(그룹 또는 개인)에 이름과 유형이 같은 카테고리가 없으면 새로 만들며,
+    /// 그룹에 새 카테고리를 만들려면 카테고리 관리 권한이 필요합니다.
+    pub async fn quick_add(
+        &self,
+        user_id: i64,
+        transaction: Transaction,
+        category_name: &str,
+        tag_ids: Vec<i64>,
+    ) -> Result<QuickAddedTransaction, AppError> {
+        let name = category_name.trim();
+        if name.is_empty() {
+            return Err(AppError::Validation(MessageKey::CategoryNameRequired));
         }
 
-        self.transaction_repo.create(&transaction).await
+        let links = TransactionLinks {
+            splits: None,
+            tag_ids: Some(tag_ids),
+        };
+        self.ensure_can_create(user_id, &transaction, &links).await?;
+
+        if let Some(group_id) = transaction.group_id {
+            let existing = self.category_repo
+                .find_by_name(Some(group_id), user_id, name, transaction.transaction_type)
+                .await?;
+            if existing.is_none() {
+                GroupPermissionPolicy::authorize(
+                    self.member_repo.as_ref(),
+                    group_id,
+                    user_id,
+                    GroupPermission::ManageCategories,
+                )
+                .await?;
+            }
+        }
+
+        let now = chrono::Utc::now().naive_utc();
+        let category = Category {
+            id: 0,
+            group_id: transaction.group_id,
+            created_by: user_id,
+            parent_id: None,
+            name: name.to_string(),
+            transaction_type: transaction.transaction_type,
+            color: None,
+            is_default: false,
+            default_key: None,
+            budget_amount: None,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let tag_ids = links.tag_ids.unwrap_or_default();
+        self.transaction_repo.create_with_category(&transaction, &category, &tag_ids).await
     }
 
-    pub async fn get_transaction(&self, id: i64) -> Result<Transaction, AppError> {
-        self.transaction_repo
+    pub async fn get_transaction(&self, user_id: i64, id: i64) -> Result<Transaction, AppError> {
+        let transaction = self.transaction_repo
             .find_by_id(id)
             .await?
-            .ok_or_else(|| AppError::NotFound(format!("Transaction with id {} not found", id)))
+            .ok_or_else(|| AppError::NotFound(MessageKey::TransactionNotFound))?;
+
+        // 접근 권한이 없는 거래는 존재 여부도 노출하지 않음
+        if !self.can_access(user_id, &transaction).await? {
+            return Err(AppError::NotFound(MessageKey::TransactionNotFound));
+        }
+
+        Ok(transaction)
     }
 
+    /// 거래를 수정합니다. `links`에서 값이 있는 분할 항목, 태그는 모두 교체합니다 (빈 목록이면 해제).
     pub async fn update_transaction(
         &self,
+        user_id: i64,
         transaction: Transaction,
+        links: TransactionLinks,
     ) -> Result<Transaction, AppError> {
-        if transaction.amount <= 0 {
-            return Err(AppError::Validation("거래 금액은 양수여야 합니다".to_string()));
-        }
+        // 저장된 거래 기준으로 권한 확인
+        let existing = self.get_transaction(user_id, transaction.id).await?;
+        self.ensure_can_update(user_id, &existing, &transaction, &links).await?;
 
-        self.transaction_repo.update(&transaction).await
+        self.transaction_repo.update(&transaction, &links).await
     }
 
-    pub async fn delete_transaction(&self, id: i64) -> Result<(), AppError> {
+    pub async fn delete_transaction(&self, user_id: i64, id: i64) -> Result<(), AppError> {
+        let existing = self.get_transaction(user_id, id).await?;
+        self.ensure_can_modify(user_id, &existing).await?;
+
         self.transaction_repo.delete(id).await
     }
 
+    /// 여러 거래를 한 번에 생성/수정/삭제합니다. `items`의 `Err`는 요청 단계에서 검증에 실패한 항목입니다.
+    ///
+    /// 모든 항목을 먼저 검증하고 권한을 확인한 뒤 한 DB 트랜잭션으로 반영하며, 결과는 `items` 순서대로 반환합니다.
+    /// `AllOrNothing`은 한 항목이라도 실패하면 아무것도 반영하지 않습니다.
+    pub async fn bulk_apply(
+        &self,
+        user_id: i64,
+        items: Vec<Result<BulkTransactionOp, AppError>>,
+        mode: BulkMode,
+    ) -> Result<Vec<BulkItemOutcome>, AppError> {
+        // 1. 검증: 항목별로 저장할 작업과 (삭제라면) 삭제 전 거래를 준비
+        let mut seen_ids = HashSet::new();
+        let mut prepared = Vec::with_capacity(items.len());
+        for item in items {
+            let result = match item {
+                Ok(op) => self.prepare_bulk_write(user_id, op, &mut seen_ids).await,
+                Err(e) => Err(e),
+            };
+            prepared.push(result);
+        }
+
+        if mode == BulkMode::AllOrNothing && prepared.iter().any(Result::is_err) {
+            return Ok(prepared
+                .into_iter()
+                .map(|result| match result {
+                    Ok(_) => BulkItemOutcome::Skipped,
+                    Err(e) => BulkItemOutcome::Failed(e),
+                })
+                .collect());
+        }
+
+        // 2. 반영: 검증을 통과한 항목만 한 트랜잭션으로 저장
+        let writes: Vec<BulkWrite> = prepared
+            .iter()
+            .filter_map(|result| result.as_ref().ok().map(|(write, _)| write.clone()))
+            .collect();
+        let written = self.transaction_repo.apply_bulk(&writes, mode).await?;
+        // AllOrNothing에서 실패한 항목이 있으면 전체가 롤백됨
+        let rolled_back = mode == BulkMode::AllOrNothing && written.iter().any(Result::is_err);
+        let mut written = written.into_iter();
+
+        // 3. 결과: 생성/수정한 거래는 저장된 값으로 다시 조회
+        let mut stages = Vec::with_capacity(prepared.len());
+        for result in prepared {
+            let stage = match result {
+                Err(e) => Err(e),
+                Ok((_, deleted)) => match written.next() {
+                    Some(Ok(id)) if !rolled_back => Ok(Some((id, deleted))),
+                    Some(Err(e)) => Err(e),
+                    _ => Ok(None),
+                },
+            };
+            stages.push(stage);
+        }
+
+        let saved_ids: Vec<i64> = stages
+            .iter()
+            .filter_map(|stage| match stage {
+                Ok(Some((id, None))) => Some(*id),
+                _ => None,
+            })
+            .collect();
+        let mut saved: HashMap<i64, Transaction> = self.transaction_repo
+            .find_by_ids(&saved_ids)
+            .await?
+            .into_iter()
+            .map(|transaction| (transaction.id, transaction))
+            .collect();
+
+        let outcomes = stages
+            .into_iter()
+            .map(|stage| match stage {
+                Ok(Some((_, Some(deleted)))) => BulkItemOutcome::Succeeded(deleted),
+                Ok(Some((id, None))) => match saved.remove(&id) {
+                    Some(transaction) => BulkItemOutcome::Succeeded(transaction),
+                    None => BulkItemOutcome::Failed(AppError::NotFound(MessageKey::TransactionNotFound)),
+                },
+                Ok(None) => BulkItemOutcome::Skipped,
+                Err(e) => {
+                    e.log_if_server_error();
+                    BulkItemOutcome::Failed(e)
+                }
+            })
+            .collect();
+
+        Ok(outcomes)
+    }
+
+    /// 이미 조회 권한을 확인한 거래들의 분할 항목과 태그를 거래 ID별로 묶어 반환합니다.
+    pub async fn find_details(
+        &self,
+        transaction_ids: &[i64],
+    ) -> Result<HashMap<i64, TransactionDetails>, AppError> {
+        let mut grouped: HashMap<i64, TransactionDetails> = HashMap::new();
+        for split in self.transaction_repo.find_splits(transaction_ids).await? {
+            grouped.entry(split.transaction_id).or_default().splits.push(split);
+        }
+        for (transaction_id, tag) in self.transaction_repo.find_tags(transaction_ids).await? {
+            grouped.entry(transaction_id).or_default().tags.push(tag);
+        }
+
+        Ok(grouped)
+    }
+
+    /// 카테고리별 지출/수입 합계와 카테고리 예산을 조회합니다. 분할된 거래는 분할 항목 기준으로 집계합니다.
+    ///
+    /// `group_id`가 주어지면 해당 그룹의 거래를, 없으면 본인 거래를 집계합니다.
+    /// `rollup`이면 하위 카테고리를 상위 카테고리에 합산합니다.
+    pub async fn category_statistics(
+        &self,
+        user_id: i64,
+        group_id: Option<i64>,
+        filter: TransactionFilter,
+        rollup: bool,
+    ) -> Result<Vec<CategoryTotal>, AppError> {
+        if let (Some(start), Some(end)) = (filter.start_date, filter.end_date) {
+            if start > end {
+                return Err(AppError::Validation(MessageKey::InvalidDateRange));
+            }
+        }
+
+        let scope = match group_id {
+            Some(group_id) => {
+                GroupPermissionPolicy::authorize(
+                    self.member_repo.as_ref(),
+                    group_id,
+                    user_id,
+                    GroupPermission::ViewGroup,
+                )
+                .await?;
+                TransactionScope::Group(group_id)
+            }
+            None => TransactionScope::Owner(user_id),
+        };
+
+        self.transaction_repo.category_totals(scope, &filter, rollup).await
+    }
+
+    /// 거래 목록을 커서 기반으로 조회합니다.
+    ///
+    /// `group_id`가 주어지면 해당 그룹의 거래를, 없으면 본인 거래를 조회합니다.
+    /// 전체 건수는 `include_total`일 때만 계산합니다.
     pub async fn list_transactions(
         &self,
+        user_id: i64,
         group_id: Option<i64>,
-        start_date: Option<chrono::NaiveDate>,
-        end_date: Option<chrono::NaiveDate>,
-        category_id: Option<i64>,
-        search: Option<String>,
+        filter: TransactionFilter,
+        sort: TransactionSort,
+        cursor: Option<TransactionCursor>,
         limit: u32,
-        offset: u32,
-    ) -> Result<Vec<Transaction>, AppError> {
-        self.transaction_repo
-            .find_by_group(
+        include_total: bool,
+    ) -> Result<TransactionPage, AppError> {
+        if let (Some(min), Some(max)) = (filter.min_amount, filter.max_amount) {
+            if min > max {
+                return Err(AppError::Validation(MessageKey::InvalidAmountRange));
+            }
+        }
+
+        // 다른 정렬로 만든 커서는 위치가 맞지 않음
+        if cursor.is_some_and(|cursor| cursor.sort != sort) {
+            return Err(AppError::Validation(MessageKey::InvalidCursor));
+        }
+
+        // 다음 페이지가 있는지 알기 위해 한 건 더 조회
+        let fetch_limit = limit + 1;
+
+        let (mut items, total) = match group_id {
+            Some(group_id) => {
+                GroupPermissionPolicy::authorize(
+                    self.member_repo.as_ref(),
+                    group_id,
+                    user_id,
+                    GroupPermission::ViewGroup,
+                )
+                .await?;
+
+                let items = self.transaction_repo
+                    .find_by_group(group_id, &filter, sort, cursor.as_ref(), fetch_limit)
+                    .await?;
+                let total = if include_total {
+                    Some(self.transaction_repo.count_by_group(group_id, &filter).await?)
+                } else {
+                    None
+                };
+
+                (items, total)
+            }
+            None => {
+                let items = self.transaction_repo
+                    .find_by_owner(user_id, &filter, sort, cursor.as_ref(), fetch_limit)
+                    .await?;
+                let total = if include_total {
+                    Some(self.transaction_repo.count_by_owner(user_id, &filter).await?)
+                } else {
+                    None
+                };
+
+                (items, total)
+            }
+        };
+
+        let has_more = items.len() > limit as usize;
+        items.truncate(limit as usize);
+        let next_cursor = if has_more {
+            items.last().map(|last| TransactionCursor::after(last, sort))
+        } else {
+            None
+        };
+
+        Ok(TransactionPage {
+            items,
+            next_cursor,
+            has_more,
+            total,
+        })
+    }
+
+    /// 가맹점, 메모, 영수증 텍스트를 전문 검색합니다. 입력 중 자동완성 용도로 관련도 순 상위만 반환합니다.
+    ///
+    /// `group_id`가 주어지면 해당 그룹의 거래를, 없으면 본인 거래를 검색합니다.
+    pub async fn search_transactions(
+        &self,
+        user_id: i64,
+        group_id: Option<i64>,
+        terms: &[String],
+        limit: u32,
+    ) -> Result<Vec<TransactionSearchHit>, AppError> {
+        let scope = match group_id {
+            Some(group_id) => {
+                GroupPermissionPolicy::authorize(
+                    self.member_repo.as_ref(),
+                    group_id,
+                    user_id,
+                    GroupPermission::ViewGroup,
+                )
+                .await?;
+                TransactionScope::Group(group_id)
+            }
+            None => TransactionScope::Owner(user_id),
+        };
+
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.transaction_repo.search(scope, terms, limit).await
+    }
+
+    /// 일괄 처리 한 건을 단건 API와 같은 규칙으로 검증합니다. 삭제는 삭제 전 거래를 함께 반환합니다.
+    async fn prepare_bulk_write(
+        &self,
+        user_id: i64,
+        op: BulkTransactionOp,
+        seen_ids: &mut HashSet<i64>,
+    ) -> Result<(BulkWrite, Option<Transaction>), AppError> {
+        match op {
+            BulkTransactionOp::Create { transaction, links } => {
+                self.ensure_can_create(user_id, &transaction, &links).await?;
+                Ok((BulkWrite::Create { transaction, links }, None))
+            }
+            BulkTransactionOp::Patch { id, patch } => {
+                if !seen_ids.insert(id) {
+                    return Err(AppError::Validation(MessageKey::DuplicateBulkItem));
+                }
+
+                let existing = self.get_transaction(user_id, id).await?;
+                let mut transaction = existing.clone();
+                let links = patch.clone().apply(&mut transaction);
+                self.ensure_can_update(user_id, &existing, &transaction, &links).await?;
+
+                Ok((BulkWrite::Update { id, patch }, None))
+            }
+            BulkTransactionOp::Delete(id) => {
+                if !seen_ids.insert(id) {
+                    return Err(AppError::Validation(MessageKey::DuplicateBulkItem));
+                }
+
+                let existing = self.get_transaction(user_id, id).await?;
+                self.ensure_can_modify(user_id, &existing).await?;
+
+                Ok((BulkWrite::Delete(id), Some(existing)))
+            }
+        }
+    }
+
+    async fn ensure_can_create(
+        &self,
+        user_id: i64,
+        transaction: &Transaction,
+        links: &TransactionLinks,
+    ) -> Result<(), AppError> {
+        // 비즈니스 로직: 검증
+        if transaction.amount <= 0 {
+            return Err(AppError::Validation(MessageKey::AmountMustBePositive));
+        }
+        validate_splits(transaction.amount, links.splits.as_deref().unwrap_or_default())?;
+
+        if let Some(group_id) = transaction.group_id {
+            GroupPermissionPolicy::authorize(
+                self.member_repo.as_ref(),
                 group_id,
-                start_date,
-                end_date,
-                category_id,
-                search.as_deref(),
-                limit,
-                offset,
+                user_id,
+                GroupPermission::CreateTransaction,
             )
-            .await
+            .await?;
+        }
+
+        let splits = links.splits.as_deref().unwrap_or_default();
+        let category_ids = linked_category_ids(transaction, splits);
+        self.ensure_category_available(transaction, &category_ids).await?;
+        if let Some(tag_ids) = &links.tag_ids {
+            self.ensure_tags_available(transaction, tag_ids).await?;
+        }
+
+        Ok(())
     }
+
+    async fn ensure_can_update(
+        &self,
+        user_id: i64,
+        existing: &Transaction,
+        transaction: &Transaction,
+        links: &TransactionLinks,
+    ) -> Result<(), AppError> {
+        if transaction.amount <= 0 {
+            return Err(AppError::Validation(MessageKey::AmountMustBePositive));
+        }
+
+        match links.splits.as_deref() {
+            Some(splits) => validate_splits(transaction.amount, splits)?,
+            // 분할 항목은 그대로 두고 금액만 바꾸면 합계가 어긋남
+            None if transaction.amount != existing.amount => {
+                if !self.transaction_repo.find_splits(&[existing.id]).await?.is_empty() {
+                    return Err(AppError::Validation(MessageKey::SplitSumMismatch));
+                }
+            }
+            None => {}
+        }
+
+        self.ensure_can_modify(user_id, existing).await?;
+
+        // 다른 그룹으로 옮기는 경우 해당 그룹에 거래를 만들 수 있어야 함
+        if let Some(group_id) = transaction.group_id {
+            if existing.group_id != Some(group_id) {
+                GroupPermissionPolicy::authorize(
+                    self.member_repo.as_ref(),
+                    group_id,
+                    user_id,
+                    GroupPermission::CreateTransaction,
+                )
+                .await?;
+            }
+        }
+
+        // 카테고리를 바꾸거나 범위를 옮기면 카테고리도 새 범위의 것이어야 함
+        let scope_changed = transaction.group_id != existing.group_id
+            || transaction.owner_user_id != existing.owner_user_id;
+        let mut category_ids = Vec::new();
+        if let Some(category_id) = transaction.category_id {
+            if scope_changed || transaction.category_id != existing.category_id {
+                category_ids.push(category_id);
+            }
+        }
+        match links.splits.as_deref() {
+            Some(splits) => {
+                category_ids.extend(splits.iter().filter_map(|split| split.category_id))
+            }
+            None if scope_changed => category_ids.extend(
+                self.transaction_repo
+                    .find_splits(&[existing.id])
+                    .await?
+                    .iter()
+                    .filter_map(|split| split.category_id),
+            ),
+            None => {}
+        }
+        self.ensure_category_available(transaction, &category_ids).await?;
+
+        match &links.tag_ids {
+            Some(tag_ids) => self.ensure_tags_available(transaction, tag_ids).await?,
+            // 범위를 옮기면 기존 태그도 새 범위에서 쓸 수 있어야 함
+            None if scope_changed => {
+                let tag_ids: Vec<i64> = self.transaction_repo
+                    .find_tags(&[existing.id])
+                    .await?
+                    .into_iter()
+                    .map(|(_, tag)| tag.id)
+                    .collect();
+                self.ensure_tags_available(transaction, &tag_ids).await?;
+            }
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    /// 거래와 분할 항목에는 같은 그룹의 카테고리나, 개인 거래라면 작성자의 개인 카테고리만 지정할 수 있습니다.
+    async fn ensure_category_available(
+        &self,
+        transaction: &Transaction,
+        category_ids: &[i64],
+    ) -> Result<(), AppError> {
+        if category_ids.is_empty() {
+            return Ok(());
+        }
+
+        let categories = self.category_repo.find_by_ids(category_ids).await?;
+        let available = |category: &Category| match transaction.group_id {
+            Some(group_id) => category.group_id == Some(group_id),
+            None => category.group_id.is_none() && category.created_by == transaction.owner_user_id,
+        };
+
+        let all_available = category_ids
+            .iter()
+            .all(|id| categories.iter().any(|category| category.id == *id && available(category)));
+        if !all_available {
+            return Err(AppError::Validation(MessageKey::CategoryNotAvailable));
+        }
+
+        Ok(())
+    }
+
+    /// 거래에는 같은 그룹의 태그나, 개인 거래라면 작성자의 개인 태그만 붙일 수 있습니다.
+    async fn ensure_tags_available(
+        &self,
+        transaction: &Transaction,
+        tag_ids: &[i64],
+    ) -> Result<(), AppError> {
+        if tag_ids.is_empty() {
+            return Ok(());
+        }
+
+        let tags = self.tag_repo.find_by_ids(tag_ids).await?;
+        let available = |tag: &Tag| match transaction.group_id {
+            Some(group_id) => tag.group_id == Some(group_id),
+            None => tag.group_id.is_none() && tag.created_by == transaction.owner_user_id,
+        };
+
+        let all_available = tag_ids
+            .iter()
+            .all(|id| tags.iter().any(|tag| tag.id == *id && available(tag)));
+        if !all_available {
+            return Err(AppError::Validation(MessageKey::TagNotAvailable));
+        }
+
+        Ok(())
+    }
+
+    async fn can_access(&self, user_id: i64, transaction: &Transaction) -> Result<bool, AppError> {
+        if transaction.owner_user_id == user_id {
+            return Ok(true);
+        }
+
+        match transaction.group_id {
+            Some(group_id) => self.is_group_member(user_id, group_id).await,
+            None => Ok(false),
+        }
+    }
+
+    /// 개인 거래는 작성자만, 그룹 거래는 역할에 따라 수정/삭제할 수 있습니다.
+    async fn ensure_can_modify(&self, user_id: i64, transaction: &Transaction) -> Result<(), AppError> {
+        let Some(group_id) = transaction.group_id else {
+            // 개인 거래는 작성자만 조회 가능하므로 이미 확인됨
+            return Ok(());
+        };
+
+        let permission = if transaction.owner_user_id == user_id {
+            GroupPermission::ManageOwnTransactions
+        } else {
+            GroupPermission::ManageAllTransactions
+        };
+
+        GroupPermissionPolicy::authorize(
+            self.member_repo.as_ref(),
+            group_id,
+            user_id,
+            permission,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn is_group_member(&self, user_id: i64, group_id: i64) -> Result<bool, AppError> {
+        self.member_repo.is_member(group_id, user_id).await
+    }
+}
+
+/// 거래와 분할 항목에 지정된 카테고리 ID
+fn linked_category_ids(transaction: &Transaction, splits: &[TransactionSplit]) -> Vec<i64> {
+    transaction
+        .category_id
+        .into_iter()
+        .chain(splits.iter().filter_map(|split| split.category_id))
+        .collect()
+}
+
+/// 분할 항목 금액은 양수이고 합계가 거래 금액과 같아야 합니다. 빈 목록은 분할하지 않은 거래입니다.
+fn validate_splits(amount: i64, splits: &[TransactionSplit]) -> Result<(), AppError> {
+    if splits.is_empty() {
+        return Ok(());
+    }
+
+    if splits.iter().any(|split| split.amount <= 0) {
+        return Err(AppError::Validation(MessageKey::AmountMustBePositive));
+    }
+
+    let total = splits
+        .iter()
+        .try_fold(0i64, |total, split| total.checked_add(split.amount));
+    if total != Some(amount) {
+        return Err(AppError::Validation(MessageKey::SplitSumMismatch));
+    }
+
+    Ok(())
 }
 
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use super::*;
+
+    fn split(category_id: Option<i64>, amount: i64) -> TransactionSplit {
+        TransactionSplit {
+            id: 0,
+            transaction_id: 0,
+            category_id,
+            amount,
+            memo: None,
+            position: 0,
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+        }
+    }
+
+    #[test]
+    fn accepts_unsplit_transaction() {
+        assert!(validate_splits(10_000, &[]).is_ok());
+    }
+
+    #[test]
+    fn accepts_splits_matching_amount() {
+        let splits = [split(Some(1), 7_000), split(None, 3_000)];
+
+        assert!(validate_splits(10_000, &splits).is_ok());
+    }
+
+    #[test]
+    fn rejects_non_positive_split_amount() {
+        let splits = [split(Some(1), 10_000), split(Some(2), 0)];
+        let negative = [split(Some(1), 12_000), split(Some(2), -2_000)];
+
+        assert!(matches!(
+            validate_splits(10_000, &splits),
+            Err(AppError::Validation(MessageKey::AmountMustBePositive)),
+        ));
+        assert!(matches!(
+            validate_splits(10_000, &negative),
+            Err(AppError::Validation(MessageKey::AmountMustBePositive)),
+        ));
+    }
+
+    #[test]
+    fn rejects_sum_mismatch() {
+        let splits = [split(Some(1), 7_000), split(Some(2), 2_000)];
+
+        assert!(matches!(
+            validate_splits(10_000, &splits),
+            Err(AppError::Validation(MessageKey::SplitSumMismatch)),
+        ));
+    }
+
+    #[test]
+    fn rejects_overflowing_sum() {
+        let splits = [split(Some(1), i64::MAX), split(Some(2), 1)];
+
+        assert!(matches!(
+            validate_splits(i64::MAX, &splits),
+            Err(AppError::Validation(MessageKey::SplitSumMismatch)),
+        ));
+    }
+}
@@ -8,11 +8,15 @@ pub struct Category {
     pub id: i64,
     pub group_id: Option<i64>,
     pub created_by: i64,
+    /// 상위 카테고리 (최상위 카테고리는 `None`)
+    pub parent_id: Option<i64>,
     pub name: String,
     #[sqlx(rename = "type")]
     pub transaction_type: TransactionType,
     pub color: Option<String>,
     pub is_default: bool,
+    /// 기본 카테고리 세트에서 만든 카테고리의 키 (예: `expense.food`)
+    pub default_key: Option<String>,
     pub budget_amount: Option<i64>,
     #[sqlx(default)]
     pub created_at: NaiveDateTime,
@@ -20,3 +24,19 @@ pub struct Category {
     pub updated_at: NaiveDateTime,
 }
 
+/// 카테고리 트리의 한 노드 (하위 카테고리는 이름순)
+#[derive(Debug, Clone)]
+pub struct CategoryNode {
+    pub category: Category,
+    pub children: Vec<CategoryNode>,
+}
+
+/// 카테고리 병합으로 대상 카테고리에 옮긴 항목 수
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CategoryMergeSummary {
+    pub transactions: u64,
+    pub splits: u64,
+    pub recurring_rules: u64,
+    pub auto_category_rules: u64,
+    pub sub_categories: u64,
+}
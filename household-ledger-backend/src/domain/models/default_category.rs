@@ -0,0 +1,96 @@
+use super::transaction::TransactionType::{self, Expense, Income, Transfer};
+use crate::i18n::Locale;
+
+/// 현재 기본 카테고리 세트 버전
+///
+/// 기본 카테고리를 추가하면 버전을 올리고 추가한 항목의 `since`를 새 버전으로 둡니다.
+/// 이미 배포된 항목의 `key`는 바꾸지 않습니다 (적용된 카테고리를 찾는 식별자).
+pub const DEFAULT_CATEGORY_VERSION: u32 = 2;
+
+/// 기본 카테고리 세트의 한 항목
+#[derive(Debug, Clone, Copy)]
+pub struct DefaultCategory {
+    /// 버전이 바뀌어도 유지되는 식별자 (`categories.default_key`)
+    pub key: &'static str,
+    /// 상위 기본 카테고리의 키 (상위 항목이 목록에서 먼저 나와야 함)
+    pub parent_key: Option<&'static str>,
+    pub transaction_type: TransactionType,
+    pub color: &'static str,
+    pub name_ko: &'static str,
+    pub name_en: &'static str,
+    /// 이 항목이 추가된 세트 버전
+    pub since: u32,
+}
+
+impl DefaultCategory {
+    pub fn name(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Ko => self.name_ko,
+            Locale::En => self.name_en,
+        }
+    }
+}
+
+/// 기본 카테고리 적용 결과
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultCategoryResult {
+    /// 적용 후 기본 카테고리 세트 버전
+    pub version: u32,
+    /// 새로 만든 카테고리 수
+    pub created: u64,
+    /// 이름과 유형이 같은 기존 카테고리를 기본 카테고리로 연결한 수
+    pub linked: u64,
+    /// 이름, 색상, 상위 카테고리를 기본값으로 되돌린 수 (초기화할 때만)
+    pub restored: u64,
+}
+
+const fn item(
+    key: &'static str,
+    parent_key: Option<&'static str>,
+    transaction_type: TransactionType,
+    color: &'static str,
+    name_ko: &'static str,
+    name_en: &'static str,
+    since: u32,
+) -> DefaultCategory {
+    DefaultCategory { key, parent_key, transaction_type, color, name_ko, name_en, since }
+}
+
+/// 기본 카테고리 세트 (상위 카테고리가 하위 카테고리보다 먼저 나옴)
+pub const DEFAULT_CATEGORIES: &[DefaultCategory] = &[
+    // 지출
+    item("expense.food", None, Expense, "#F97316", "식비", "Food", 1),
+    item("expense.food.dining", Some("expense.food"), Expense, "#FB923C", "외식", "Dining out", 1),
+    item("expense.food.groceries", Some("expense.food"), Expense, "#FDBA74", "장보기", "Groceries", 1),
+    item("expense.food.delivery", Some("expense.food"), Expense, "#EA580C", "배달", "Delivery", 1),
+    item("expense.food.cafe", Some("expense.food"), Expense, "#C2410C", "카페/간식", "Cafe & snacks", 1),
+    item("expense.housing", None, Expense, "#8B5CF6", "주거", "Housing", 1),
+    item("expense.housing.maintenance", Some("expense.housing"), Expense, "#A78BFA", "관리비", "Maintenance fees", 1),
+    item("expense.housing.rent", Some("expense.housing"), Expense, "#7C3AED", "월세", "Rent", 1),
+    item("expense.housing.utilities", Some("expense.housing"), Expense, "#6D28D9", "공과금", "Utilities", 1),
+    item("expense.transport", None, Expense, "#3B82F6", "교통", "Transport", 1),
+    item("expense.transport.public", Some("expense.transport"), Expense, "#60A5FA", "대중교통", "Public transit", 1),
+    item("expense.transport.fuel", Some("expense.transport"), Expense, "#2563EB", "주유", "Fuel", 1),
+    item("expense.living", None, Expense, "#14B8A6", "생활용품", "Household supplies", 1),
+    item("expense.health", None, Expense, "#EF4444", "의료/건강", "Health", 1),
+    item("expense.education", None, Expense, "#6366F1", "교육", "Education", 1),
+    item("expense.leisure", None, Expense, "#EC4899", "문화/여가", "Leisure", 1),
+    item("expense.clothing", None, Expense, "#F472B6", "의류/미용", "Clothing & beauty", 1),
+    item("expense.communication", None, Expense, "#0EA5E9", "통신", "Phone & internet", 1),
+    item("expense.gifts", None, Expense, "#A855F7", "경조사/선물", "Gifts & occasions", 1),
+    item("expense.other", None, Expense, "#6B7280", "기타 지출", "Other expenses", 1),
+    item("expense.subscriptions", None, Expense, "#F59E0B", "구독", "Subscriptions", 2),
+    item("expense.pets", None, Expense, "#D97706", "반려동물", "Pets", 2),
+    item("expense.insurance", None, Expense, "#7C3AED", "보험", "Insurance", 2),
+    // 수입
+    item("income.salary", None, Income, "#22C55E", "급여", "Salary", 1),
+    item("income.bonus", None, Income, "#16A34A", "상여", "Bonus", 1),
+    item("income.side", None, Income, "#84CC16", "부수입", "Side income", 1),
+    item("income.interest", None, Income, "#10B981", "이자/배당", "Interest & dividends", 1),
+    item("income.other", None, Income, "#6B7280", "기타 수입", "Other income", 1),
+    // 이체
+    item("transfer.savings", None, Transfer, "#0EA5E9", "저축", "Savings", 1),
+    item("transfer.investment", None, Transfer, "#0284C7", "투자", "Investments", 1),
+    item("transfer.card_payment", None, Transfer, "#64748B", "카드 대금", "Card payment", 1),
+    item("transfer.account", None, Transfer, "#94A3B8", "계좌 이체", "Account transfer", 1),
+];
@@ -11,3 +11,25 @@ pub struct Group {
     pub created_at: NaiveDateTime,
 }
 
+/// 그룹 내 사용자의 역할 (권한이 높은 순)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "UPPERCASE")]
+#[sqlx(type_name = "enum", rename_all = "UPPERCASE")]
+pub enum GroupRole {
+    Owner,
+    Admin,
+    Editor,
+    Viewer,
+}
+
+impl GroupRole {
+    /// 역할 간 비교용 등급 (클수록 권한이 많음)
+    pub fn level(self) -> u8 {
+        match self {
+            GroupRole::Owner => 3,
+            GroupRole::Admin => 2,
+            GroupRole::Editor => 1,
+            GroupRole::Viewer => 0,
+        }
+    }
+}
@@ -8,8 +8,16 @@ pub struct GroupInvite {
     pub group_id: i64,
     pub code: String,
     pub created_by: i64,
+    /// 최대 사용 횟수 (None이면 제한 없음)
+    pub max_uses: Option<i32>,
+    pub use_count: i32,
     pub expires_at: NaiveDateTime,
     #[sqlx(default)]
     pub created_at: NaiveDateTime,
 }
 
+impl GroupInvite {
+    pub fn is_active(&self, now: NaiveDateTime) -> bool {
+        self.expires_at > now && self.max_uses.is_none_or(|max| self.use_count < max)
+    }
+}
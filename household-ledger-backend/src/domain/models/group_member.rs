@@ -0,0 +1,14 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use super::GroupRole;
+
+/// 그룹 멤버십
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct GroupMember {
+    pub group_id: i64,
+    pub user_id: i64,
+    pub role: GroupRole,
+    #[sqlx(default)]
+    pub joined_at: NaiveDateTime,
+}
@@ -3,25 +3,46 @@ pub mod auto_category_rule;
 pub mod budget;
 pub mod category;
 pub mod currency_preference;
+pub mod default_category;
 pub mod exchange_rate;
 pub mod group;
 pub mod group_invite;
+pub mod group_member;
 pub mod receipt;
 pub mod recurring_rule;
+pub mod session;
 pub mod tag;
 pub mod transaction;
+pub mod transaction_bulk;
+pub mod transaction_query;
+pub mod transaction_split;
 pub mod user;
+pub mod user_token;
 
 pub use attachment::Attachment;
 pub use auto_category_rule::{AutoCategoryRule, PatternType};
 pub use budget::{Budget, BudgetStatus, OwnerType};
-pub use category::Category;
+pub use category::{Category, CategoryMergeSummary, CategoryNode};
 pub use currency_preference::CurrencyPreference;
+pub use default_category::{
+    DefaultCategory, DefaultCategoryResult, DEFAULT_CATEGORIES, DEFAULT_CATEGORY_VERSION,
+};
 pub use exchange_rate::ExchangeRate;
-pub use group::Group;
+pub use group::{Group, GroupRole};
 pub use group_invite::GroupInvite;
+pub use group_member::GroupMember;
 pub use receipt::{Receipt, OcrStatus};
 pub use recurring_rule::{RecurringRule, RecurringFrequency};
+pub use session::{DeviceInfo, Session};
 pub use tag::Tag;
-pub use transaction::{Transaction, TransactionType};
+pub use transaction::{
+    QuickAddedTransaction, Transaction, TransactionDetails, TransactionLinks, TransactionType,
+};
+pub use transaction_bulk::{BulkMode, BulkTransactionOp, BulkWrite, TransactionPatch};
+pub use transaction_query::{
+    SortDirection, TransactionCursor, TransactionFilter, TransactionPage, TransactionScope,
+    TransactionSearchHit, TransactionSort, TransactionSortField,
+};
+pub use transaction_split::{CategoryTotal, TransactionSplit};
 pub use user::User;
+pub use user_token::{TokenPurpose, UserToken};
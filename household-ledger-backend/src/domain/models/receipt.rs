@@ -19,6 +19,8 @@ pub struct Receipt {
     pub group_id: Option<i64>,
     pub image_url: String,
     pub ocr_result: Option<serde_json::Value>,
+    /// OCR로 읽은 원문 텍스트 (전문 검색용)
+    pub ocr_text: Option<String>,
     #[sqlx(rename = "ocr_status")]
     pub ocr_status: OcrStatus,
     pub extracted_amount: Option<i64>,
@@ -1,8 +1,10 @@
 use chrono::{NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use super::{Category, Tag, TransactionSplit};
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "UPPERCASE")]
 #[sqlx(type_name = "enum", rename_all = "UPPERCASE")]
 pub enum TransactionType {
     Expense,
@@ -22,7 +24,6 @@ pub struct Transaction {
     pub currency_code: Option<String>,
     pub original_amount: Option<i64>,
     pub category_id: Option<i64>,
-    pub tag_id: Option<i64>,
     pub recurring_rule_id: Option<i64>,
     pub receipt_id: Option<i64>,
     pub merchant: Option<String>,
@@ -33,3 +34,29 @@ pub struct Transaction {
     pub updated_at: NaiveDateTime,
 }
 
+/// 카테고리 이름으로 거래를 간편 입력한 결과
+#[derive(Debug, Clone)]
+pub struct QuickAddedTransaction {
+    pub transaction: Transaction,
+    pub category: Category,
+    /// 이름에 맞는 카테고리가 없어 새로 만들었는지 여부
+    pub category_created: bool,
+}
+
+/// 거래와 함께 저장할 분할 항목과 태그
+///
+/// 수정에서는 `None`인 항목을 그대로 두고, 생성에서는 `None`을 빈 목록으로 취급합니다.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionLinks {
+    /// 분할 항목 전체 교체 (빈 목록이면 분할 해제)
+    pub splits: Option<Vec<TransactionSplit>>,
+    /// 태그 전체 교체 (빈 목록이면 태그 해제)
+    pub tag_ids: Option<Vec<i64>>,
+}
+
+/// 조회한 거래에 딸린 분할 항목과 태그
+#[derive(Debug, Clone, Default)]
+pub struct TransactionDetails {
+    pub splits: Vec<TransactionSplit>,
+    pub tags: Vec<Tag>,
+}
@@ -0,0 +1,95 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use super::{Transaction, TransactionLinks, TransactionSplit, TransactionType};
+
+/// 일괄 처리 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkMode {
+    /// 한 항목이라도 실패하면 아무것도 반영하지 않음
+    #[default]
+    AllOrNothing,
+    /// 실패한 항목만 건너뛰고 나머지는 반영
+    BestEffort,
+}
+
+/// 거래 부분 수정 (값이 있는 필드만 변경, `recurring_rule_id`와 `receipt_id`는 유지)
+#[derive(Debug, Clone, Default)]
+pub struct TransactionPatch {
+    pub group_id: Option<i64>,
+    pub amount: Option<i64>,
+    pub currency_code: Option<String>,
+    pub original_amount: Option<i64>,
+    pub category_id: Option<i64>,
+    pub merchant: Option<String>,
+    pub memo: Option<String>,
+    pub transaction_type: Option<TransactionType>,
+    pub date: Option<NaiveDate>,
+    /// 분할 항목 전체 교체 (빈 목록이면 분할 해제, `None`이면 유지)
+    pub splits: Option<Vec<TransactionSplit>>,
+    /// 태그 전체 교체 (빈 목록이면 태그 해제, `None`이면 유지)
+    pub tag_ids: Option<Vec<i64>>,
+}
+
+impl TransactionPatch {
+    /// 거래 필드를 바꾸고, 함께 교체할 분할 항목과 태그를 반환합니다.
+    pub fn apply(self, transaction: &mut Transaction) -> TransactionLinks {
+        if let Some(group_id) = self.group_id {
+            transaction.group_id = Some(group_id);
+        }
+        if let Some(amount) = self.amount {
+            transaction.amount = amount;
+        }
+        if let Some(currency_code) = self.currency_code {
+            transaction.currency_code = Some(currency_code);
+        }
+        if let Some(original_amount) = self.original_amount {
+            transaction.original_amount = Some(original_amount);
+        }
+        if let Some(category_id) = self.category_id {
+            transaction.category_id = Some(category_id);
+        }
+        if let Some(merchant) = self.merchant {
+            transaction.merchant = Some(merchant);
+        }
+        if let Some(memo) = self.memo {
+            transaction.memo = Some(memo);
+        }
+        if let Some(transaction_type) = self.transaction_type {
+            transaction.transaction_type = transaction_type;
+        }
+        if let Some(date) = self.date {
+            transaction.date = date;
+        }
+
+        TransactionLinks {
+            splits: self.splits,
+            tag_ids: self.tag_ids,
+        }
+    }
+
+    /// 함께 교체할 분할 항목과 태그
+    pub fn links(&self) -> TransactionLinks {
+        TransactionLinks {
+            splits: self.splits.clone(),
+            tag_ids: self.tag_ids.clone(),
+        }
+    }
+}
+
+/// 일괄 처리 요청 한 건
+#[derive(Debug, Clone)]
+pub enum BulkTransactionOp {
+    Create { transaction: Transaction, links: TransactionLinks },
+    Patch { id: i64, patch: TransactionPatch },
+    Delete(i64),
+}
+
+/// 검증과 권한 확인을 마치고 저장소에 넘기는 작업 한 건
+#[derive(Debug, Clone)]
+pub enum BulkWrite {
+    Create { transaction: Transaction, links: TransactionLinks },
+    /// 검증 이후 다른 요청이 바꾼 필드를 덮어쓰지 않도록 `patch`에서 값이 있는 필드만 반영
+    Update { id: i64, patch: TransactionPatch },
+    Delete(i64),
+}
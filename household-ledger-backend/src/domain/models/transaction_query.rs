@@ -0,0 +1,242 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::Deserialize;
+use super::{Transaction, TransactionType};
+
+/// 거래 목록 조회 조건 (모든 조건은 AND로 결합)
+#[derive(Debug, Clone, Default)]
+pub struct TransactionFilter {
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub transaction_type: Option<TransactionType>,
+    pub min_amount: Option<i64>,
+    pub max_amount: Option<i64>,
+    /// 비어 있으면 카테고리 조건 없음
+    pub category_ids: Vec<i64>,
+    /// 비어 있으면 태그 조건 없음
+    pub tag_ids: Vec<i64>,
+    /// 가맹점 이름 (정확히 일치)
+    pub merchant: Option<String>,
+    /// 작성한 그룹 멤버
+    pub owner_user_id: Option<i64>,
+    pub currency_code: Option<String>,
+    pub has_receipt: Option<bool>,
+    /// 반복 거래 규칙으로 생성된 거래만(`true`) 또는 직접 입력한 거래만(`false`)
+    pub from_recurring: Option<bool>,
+    /// 가맹점/메모 검색어 (전문 검색, 한 글자 단어만 있으면 부분 일치)
+    pub search: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionSortField {
+    #[default]
+    Date,
+    Amount,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// 거래 목록 정렬 (같은 값은 `created_at`, `id` 순으로 이어서 정렬)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransactionSort {
+    pub field: TransactionSortField,
+    pub direction: SortDirection,
+}
+
+impl TransactionSort {
+    pub fn as_str(&self) -> &'static str {
+        match (self.field, self.direction) {
+            (TransactionSortField::Date, SortDirection::Desc) => "date_desc",
+            (TransactionSortField::Date, SortDirection::Asc) => "date_asc",
+            (TransactionSortField::Amount, SortDirection::Desc) => "amount_desc",
+            (TransactionSortField::Amount, SortDirection::Asc) => "amount_asc",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        let (field, direction) = match value {
+            "date_desc" => (TransactionSortField::Date, SortDirection::Desc),
+            "date_asc" => (TransactionSortField::Date, SortDirection::Asc),
+            "amount_desc" => (TransactionSortField::Amount, SortDirection::Desc),
+            "amount_asc" => (TransactionSortField::Amount, SortDirection::Asc),
+            _ => return None,
+        };
+
+        Some(Self { field, direction })
+    }
+}
+
+/// 거래 목록의 페이지 위치 (이전 페이지 마지막 거래의 정렬 키)
+///
+/// 클라이언트에는 [`TransactionCursor::encode`]로 만든 불투명한 문자열로 전달합니다.
+/// 커서를 만든 정렬과 다른 정렬로는 사용할 수 없습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionCursor {
+    pub sort: TransactionSort,
+    pub date: NaiveDate,
+    pub created_at: NaiveDateTime,
+    pub amount: i64,
+    pub id: i64,
+}
+
+impl TransactionCursor {
+    const DATETIME_FORMAT: &'static str = "%Y-%m-%dT%H:%M:%S%.f";
+
+    pub fn after(transaction: &Transaction, sort: TransactionSort) -> Self {
+        Self {
+            sort,
+            date: transaction.date,
+            created_at: transaction.created_at,
+            amount: transaction.amount,
+            id: transaction.id,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let raw = format!(
+            "{}|{}|{}|{}|{}",
+            self.sort.as_str(),
+            self.date,
+            self.created_at.format(Self::DATETIME_FORMAT),
+            self.amount,
+            self.id
+        );
+        URL_SAFE_NO_PAD.encode(raw)
+    }
+
+    /// 형식이 잘못된 커서는 `None`
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let bytes = URL_SAFE_NO_PAD.decode(cursor).ok()?;
+        let raw = String::from_utf8(bytes).ok()?;
+        let mut parts = raw.split('|');
+
+        let sort = TransactionSort::parse(parts.next()?)?;
+        let date = parts.next()?.parse().ok()?;
+        let created_at = NaiveDateTime::parse_from_str(parts.next()?, Self::DATETIME_FORMAT).ok()?;
+        let amount = parts.next()?.parse().ok()?;
+        let id = parts.next()?.parse().ok()?;
+
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self { sort, date, created_at, amount, id })
+    }
+}
+
+/// 커서 기반 거래 목록 한 페이지
+#[derive(Debug, Clone)]
+pub struct TransactionPage {
+    pub items: Vec<Transaction>,
+    /// 다음 페이지 조회에 쓸 커서 (마지막 페이지면 `None`)
+    pub next_cursor: Option<TransactionCursor>,
+    pub has_more: bool,
+    /// 요청한 경우에만 계산하는 전체 건수
+    pub total: Option<i64>,
+}
+
+/// 거래를 조회할 범위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionScope {
+    /// 그룹의 모든 거래
+    Group(i64),
+    /// 본인이 작성한 거래
+    Owner(i64),
+}
+
+/// 전문 검색 결과 한 건
+#[derive(Debug, Clone)]
+pub struct TransactionSearchHit {
+    pub transaction: Transaction,
+    /// 관련도 (가맹점/메모와 영수증 점수의 합, 클수록 관련 있음)
+    pub score: f64,
+    /// 연결된 영수증의 OCR 텍스트
+    pub receipt_text: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor(sort: TransactionSort, created_at: &str) -> TransactionCursor {
+        TransactionCursor {
+            sort,
+            date: NaiveDate::from_ymd_opt(2024, 3, 9).unwrap(),
+            created_at: NaiveDateTime::parse_from_str(created_at, "%Y-%m-%d %H:%M:%S%.f").unwrap(),
+            amount: 12_500,
+            id: 42,
+        }
+    }
+
+    fn encode_raw(raw: &str) -> String {
+        URL_SAFE_NO_PAD.encode(raw)
+    }
+
+    #[test]
+    fn round_trips_every_sort() {
+        for field in [TransactionSortField::Date, TransactionSortField::Amount] {
+            for direction in [SortDirection::Asc, SortDirection::Desc] {
+                let original = cursor(TransactionSort { field, direction }, "2024-03-09 21:05:13");
+
+                assert_eq!(TransactionCursor::decode(&original.encode()), Some(original));
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_sub_second_precision() {
+        let original = cursor(TransactionSort::default(), "2024-03-09 21:05:13.123456");
+
+        assert_eq!(TransactionCursor::decode(&original.encode()), Some(original));
+    }
+
+    #[test]
+    fn encodes_url_safe_without_padding() {
+        let encoded = cursor(TransactionSort::default(), "2024-03-09 21:05:13.5").encode();
+
+        assert!(!encoded.contains(['+', '/', '=']));
+    }
+
+    #[test]
+    fn rejects_malformed_cursors() {
+        assert_eq!(TransactionCursor::decode(""), None);
+        assert_eq!(TransactionCursor::decode("not base64!"), None);
+        assert_eq!(TransactionCursor::decode(&URL_SAFE_NO_PAD.encode([0xff, 0xfe])), None);
+
+        let malformed = [
+            // 필드 부족 / 초과
+            "date_desc|2024-03-09|2024-03-09T21:05:13|12500",
+            "date_desc|2024-03-09|2024-03-09T21:05:13|12500|42|1",
+            // 알 수 없는 정렬, 잘못된 날짜/시각/숫자
+            "name_desc|2024-03-09|2024-03-09T21:05:13|12500|42",
+            "date_desc|2024-13-09|2024-03-09T21:05:13|12500|42",
+            "date_desc|2024-03-09|yesterday|12500|42",
+            "date_desc|2024-03-09|2024-03-09T21:05:13|12.5|42",
+        ];
+        for raw in malformed {
+            assert_eq!(TransactionCursor::decode(&encode_raw(raw)), None, "{}", raw);
+        }
+    }
+
+    #[test]
+    fn decodes_hand_written_cursor() {
+        let raw = "amount_asc|2024-03-09|2024-03-09T21:05:13|12500|42";
+        let decoded = TransactionCursor::decode(&encode_raw(raw));
+
+        assert_eq!(
+            decoded,
+            Some(cursor(
+                TransactionSort { field: TransactionSortField::Amount, direction: SortDirection::Asc },
+                "2024-03-09 21:05:13",
+            )),
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use super::TransactionType;
+
+/// 거래의 분할 항목 (항목별 카테고리와 금액, 합계는 부모 거래 금액과 같음)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TransactionSplit {
+    pub id: i64,
+    pub transaction_id: i64,
+    pub category_id: Option<i64>,
+    pub amount: i64,
+    pub memo: Option<String>,
+    /// 거래 안에서의 순서 (0부터)
+    pub position: i32,
+    #[sqlx(default)]
+    pub created_at: NaiveDateTime,
+    #[sqlx(default)]
+    pub updated_at: NaiveDateTime,
+}
+
+/// 카테고리별 집계 (분할된 거래는 분할 항목 기준)
+#[derive(Debug, Clone)]
+pub struct CategoryTotal {
+    /// 카테고리가 없는 거래는 `None`
+    pub category_id: Option<i64>,
+    pub category_name: Option<String>,
+    /// 상위 카테고리 (하위 카테고리를 합산한 집계에서는 항상 `None`)
+    pub parent_id: Option<i64>,
+    pub transaction_type: TransactionType,
+    pub total_amount: i64,
+    /// 집계에 포함된 거래 수 (분할 항목이 여러 개여도 한 건)
+    pub transaction_count: i64,
+    /// 카테고리 예산 (하위 카테고리를 합산하면 상위 카테고리 예산, 없으면 하위 카테고리 예산의 합)
+    pub budget_amount: Option<i64>,
+}
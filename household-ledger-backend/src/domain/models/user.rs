@@ -1,12 +1,15 @@
-use chrono::{NaiveDateTime, DateTime, Utc};
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use crate::i18n::Locale;
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct User {
     pub id: i64,
     pub email: String,
     pub password_hash: String,
+    /// 이메일 인증 완료 시각 (미인증이면 None)
+    pub email_verified_at: Option<NaiveDateTime>,
     pub nickname: String,
     pub avatar_url: Option<String>,
     pub group_id: Option<i64>,
@@ -16,3 +19,17 @@ pub struct User {
     pub created_at: NaiveDateTime,
 }
 
+impl User {
+    pub fn is_email_verified(&self) -> bool {
+        self.email_verified_at.is_some()
+    }
+
+    /// 사용자 설정(`settings.language`)에 저장된 언어
+    pub fn preferred_locale(&self) -> Option<Locale> {
+        self.settings
+            .as_ref()?
+            .get("language")?
+            .as_str()
+            .and_then(Locale::from_tag)
+    }
+}
@@ -0,0 +1,23 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "enum", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TokenPurpose {
+    PasswordReset,
+    EmailVerification,
+}
+
+/// 이메일로 전달되는 일회용 토큰 (해시만 저장)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct UserToken {
+    pub id: i64,
+    pub user_id: i64,
+    pub purpose: TokenPurpose,
+    pub token_hash: String,
+    pub expires_at: NaiveDateTime,
+    pub used_at: Option<NaiveDateTime>,
+    #[sqlx(default)]
+    pub created_at: NaiveDateTime,
+}
@@ -1,19 +1,68 @@
 use async_trait::async_trait;
-use crate::domain::models::{Category, TransactionType};
+use crate::domain::models::{
+    Category, CategoryMergeSummary, DefaultCategory, DefaultCategoryResult, TransactionType,
+};
 use crate::errors::AppError;
+use crate::i18n::Locale;
 
 #[async_trait]
 pub trait CategoryRepository: Send + Sync {
     async fn create(&self, category: &Category) -> Result<Category, AppError>;
     async fn find_by_id(&self, id: i64) -> Result<Option<Category>, AppError>;
+    async fn find_by_ids(&self, ids: &[i64]) -> Result<Vec<Category>, AppError>;
     async fn find_by_group(
         &self,
-        group_id: Option<i64>,
+        group_id: i64,
+        transaction_type: Option<TransactionType>,
+    ) -> Result<Vec<Category>, AppError>;
+    /// 그룹에 속하지 않은 개인 카테고리
+    async fn find_personal(
+        &self,
+        created_by: i64,
         transaction_type: Option<TransactionType>,
     ) -> Result<Vec<Category>, AppError>;
     async fn find_by_creator(&self, created_by: i64) -> Result<Vec<Category>, AppError>;
+    /// 그룹 카테고리는 `group_id`로, 개인 카테고리(`group_id` 없음)는 `created_by`로 범위를 정해 이름과 유형이 같은 카테고리를 찾습니다.
+    async fn find_by_name(
+        &self,
+        group_id: Option<i64>,
+        created_by: i64,
+        name: &str,
+        transaction_type: TransactionType,
+    ) -> Result<Option<Category>, AppError>;
     async fn update(&self, category: &Category) -> Result<Category, AppError>;
     async fn delete(&self, id: i64) -> Result<(), AppError>;
-    async fn has_transactions(&self, id: i64) -> Result<bool, AppError>;
+    /// 한 트랜잭션으로 `source_id`의 거래, 분할 항목, 반복 거래 규칙, 자동 분류 규칙, 하위 카테고리를
+    /// `target_id` 카테고리로 옮긴 뒤 `source_id`를 삭제합니다.
+    ///
+    /// 두 카테고리를 잠근 값으로 범위, 유형, 단계 제한을 확인하고 대상의 상위 카테고리를 정하며,
+    /// `combine_budget`이면 원본 예산을 대상 예산에 더합니다.
+    async fn merge(
+        &self,
+        source_id: i64,
+        target_id: i64,
+        combine_budget: bool,
+    ) -> Result<CategoryMergeSummary, AppError>;
+    /// 카테고리가 지정된 거래 수 (분할 항목으로 연결된 거래 포함)
+    async fn count_transactions(&self, id: i64) -> Result<i64, AppError>;
+    /// 개인 범위(`group_id` 없음, `created_by`의 카테고리) 또는 그룹에 마지막으로 적용한 기본 카테고리 버전과 언어
+    async fn find_default_version(
+        &self,
+        group_id: Option<i64>,
+        created_by: i64,
+    ) -> Result<Option<(u32, Locale)>, AppError>;
+    /// 한 트랜잭션으로 `defaults`를 범위에 적용하고 적용한 버전과 언어를 기록합니다.
+    ///
+    /// 같은 `default_key`의 카테고리가 이미 있으면 새로 만들지 않고, 키 없이 이름과 유형이 같은
+    /// 카테고리가 있으면 그 카테고리를 기본 카테고리로 연결합니다.
+    /// `restore`면 이미 있는 기본 카테고리의 이름, 색상, 상위 카테고리도 기본값으로 되돌립니다.
+    async fn apply_defaults(
+        &self,
+        group_id: Option<i64>,
+        created_by: i64,
+        defaults: &[DefaultCategory],
+        version: u32,
+        locale: Locale,
+        restore: bool,
+    ) -> Result<DefaultCategoryResult, AppError>;
 }
-
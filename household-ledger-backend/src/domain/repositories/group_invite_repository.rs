@@ -5,9 +5,14 @@ use crate::errors::AppError;
 #[async_trait]
 pub trait GroupInviteRepository: Send + Sync {
     async fn create(&self, invite: &GroupInvite) -> Result<GroupInvite, AppError>;
+    async fn find_by_id(&self, id: i64) -> Result<Option<GroupInvite>, AppError>;
     async fn find_by_code(&self, code: &str) -> Result<Option<GroupInvite>, AppError>;
     async fn find_by_group(&self, group_id: i64) -> Result<Vec<GroupInvite>, AppError>;
+    /// 만료되지 않았고 사용 횟수가 남은 초대 코드만 조회합니다.
+    async fn find_active_by_group(&self, group_id: i64) -> Result<Vec<GroupInvite>, AppError>;
+    /// 초대 코드를 사용해 사용자를 그룹 멤버로 추가하고 사용 횟수를 올립니다 (단일 트랜잭션).
+    async fn redeem(&self, code: &str, user_id: i64) -> Result<GroupInvite, AppError>;
     async fn delete(&self, id: i64) -> Result<(), AppError>;
+    /// 만료되었거나 사용 횟수를 모두 소진한 초대 코드를 삭제합니다.
     async fn delete_expired(&self) -> Result<u64, AppError>;
 }
-
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use crate::domain::models::{GroupMember, GroupRole};
+use crate::errors::AppError;
+
+#[async_trait]
+pub trait GroupMemberRepository: Send + Sync {
+    async fn is_member(&self, group_id: i64, user_id: i64) -> Result<bool, AppError>;
+    /// 멤버의 역할 (멤버가 아니면 None)
+    async fn find_role(&self, group_id: i64, user_id: i64) -> Result<Option<GroupRole>, AppError>;
+    async fn find_by_group(&self, group_id: i64) -> Result<Vec<GroupMember>, AppError>;
+    async fn find_by_user(&self, user_id: i64) -> Result<Vec<GroupMember>, AppError>;
+    async fn update_role(&self, group_id: i64, user_id: i64, role: GroupRole) -> Result<(), AppError>;
+    /// 멤버십을 삭제합니다. 멤버가 아니었으면 `false`.
+    async fn remove(&self, group_id: i64, user_id: i64) -> Result<bool, AppError>;
+}
@@ -4,11 +4,19 @@ use crate::errors::AppError;
 
 #[async_trait]
 pub trait GroupRepository: Send + Sync {
+    /// 그룹을 만들고 소유자를 첫 멤버로 등록합니다 (단일 트랜잭션).
     async fn create(&self, group: &Group) -> Result<Group, AppError>;
     async fn find_by_id(&self, id: i64) -> Result<Option<Group>, AppError>;
     async fn find_by_owner(&self, owner_id: i64) -> Result<Vec<Group>, AppError>;
+    /// 사용자가 멤버인 그룹 목록 (참여 순)
+    async fn find_by_member(&self, user_id: i64) -> Result<Vec<Group>, AppError>;
     async fn update(&self, group: &Group) -> Result<Group, AppError>;
+    /// 소유자를 바꾸고 이전 소유자는 ADMIN, 새 소유자는 OWNER 역할로 변경합니다 (단일 트랜잭션).
+    async fn transfer_ownership(&self, group_id: i64, from_user_id: i64, to_user_id: i64) -> Result<Group, AppError>;
+    /// 소유자 외의 멤버가 없을 때만 그룹을 삭제합니다 (단일 트랜잭션, 있으면 `Conflict(GroupHasMembers)`).
     async fn delete(&self, id: i64) -> Result<(), AppError>;
     async fn has_members(&self, id: i64) -> Result<bool, AppError>;
+    /// 소유자를 포함한 멤버 수
+    async fn count_members(&self, id: i64) -> Result<i64, AppError>;
 }
 
@@ -2,20 +2,26 @@ pub mod budget_repository;
 pub mod category_repository;
 pub mod exchange_rate_repository;
 pub mod group_invite_repository;
+pub mod group_member_repository;
 pub mod group_repository;
 pub mod receipt_repository;
 pub mod recurring_rule_repository;
+pub mod session_repository;
 pub mod tag_repository;
 pub mod transaction_repository;
 pub mod user_repository;
+pub mod user_token_repository;
 
 pub use budget_repository::BudgetRepository;
 pub use category_repository::CategoryRepository;
 pub use exchange_rate_repository::ExchangeRateRepository;
 pub use group_invite_repository::GroupInviteRepository;
+pub use group_member_repository::GroupMemberRepository;
 pub use group_repository::GroupRepository;
 pub use receipt_repository::ReceiptRepository;
 pub use recurring_rule_repository::RecurringRuleRepository;
+pub use session_repository::SessionRepository;
 pub use tag_repository::TagRepository;
 pub use transaction_repository::TransactionRepository;
 pub use user_repository::UserRepository;
+pub use user_token_repository::UserTokenRepository;
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use crate::domain::models::{DeviceInfo, Session};
+use crate::errors::AppError;
+
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    async fn create(&self, session: &Session) -> Result<Session, AppError>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<Session>, AppError>;
+    async fn find_active_by_user(&self, user_id: i64) -> Result<Vec<Session>, AppError>;
+    /// 현재 jti가 일치하는 활성 세션에 한해 새 jti로 교체합니다. 교체되지 않았으면 `false`.
+    async fn rotate(
+        &self,
+        id: &str,
+        current_jti: &str,
+        new_jti: &str,
+        expires_at: NaiveDateTime,
+        device: &DeviceInfo,
+    ) -> Result<bool, AppError>;
+    async fn revoke(&self, id: &str) -> Result<(), AppError>;
+    /// 사용자의 모든 활성 세션을 폐기합니다. `except`로 지정한 세션은 유지합니다.
+    async fn revoke_all_for_user(&self, user_id: i64, except: Option<&str>) -> Result<u64, AppError>;
+}
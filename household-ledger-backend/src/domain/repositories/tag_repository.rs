@@ -6,9 +6,15 @@ use crate::errors::AppError;
 pub trait TagRepository: Send + Sync {
     async fn create(&self, tag: &Tag) -> Result<Tag, AppError>;
     async fn find_by_id(&self, id: i64) -> Result<Option<Tag>, AppError>;
-    async fn find_by_group(&self, group_id: Option<i64>) -> Result<Vec<Tag>, AppError>;
+    async fn find_by_ids(&self, ids: &[i64]) -> Result<Vec<Tag>, AppError>;
+    async fn find_by_group(&self, group_id: i64) -> Result<Vec<Tag>, AppError>;
+    /// 그룹에 속하지 않은 개인 태그
+    async fn find_personal(&self, created_by: i64) -> Result<Vec<Tag>, AppError>;
     async fn find_by_creator(&self, created_by: i64) -> Result<Vec<Tag>, AppError>;
     async fn update(&self, tag: &Tag) -> Result<Tag, AppError>;
     async fn delete(&self, id: i64) -> Result<(), AppError>;
+    /// `source_id` 태그가 붙은 거래를 모두 `target_id` 태그로 옮기고 `source_id` 태그를 삭제합니다.
+    ///
+    /// 옮긴 거래 수를 반환합니다 (이미 두 태그가 모두 붙어 있던 거래 포함).
+    async fn merge(&self, source_id: i64, target_id: i64) -> Result<u64, AppError>;
 }
-
@@ -1,37 +1,86 @@
 use async_trait::async_trait;
-use chrono::NaiveDate;
-use crate::domain::models::Transaction;
+use crate::domain::models::{
+    BulkMode, BulkWrite, Category, CategoryTotal, QuickAddedTransaction, Tag, Transaction,
+    TransactionCursor, TransactionFilter, TransactionLinks, TransactionScope,
+    TransactionSearchHit, TransactionSort, TransactionSplit,
+};
 use crate::errors::AppError;
 
 #[async_trait]
 pub trait TransactionRepository: Send + Sync {
-    async fn create(&self, transaction: &Transaction) -> Result<Transaction, AppError>;
+    /// 거래와 분할 항목, 태그를 한 트랜잭션으로 저장합니다.
+    async fn create(
+        &self,
+        transaction: &Transaction,
+        links: &TransactionLinks,
+    ) -> Result<Transaction, AppError>;
+    /// `category`와 같은 이름의 카테고리를 찾거나 만든 뒤 그 카테고리로 거래를 만듭니다.
+    ///
+    /// 카테고리 생성과 거래, 태그 저장은 한 트랜잭션으로 처리되며 `transaction.category_id`는 무시합니다.
+    async fn create_with_category(
+        &self,
+        transaction: &Transaction,
+        category: &Category,
+        tag_ids: &[i64],
+    ) -> Result<QuickAddedTransaction, AppError>;
     async fn find_by_id(&self, id: i64) -> Result<Option<Transaction>, AppError>;
+    async fn find_by_ids(&self, ids: &[i64]) -> Result<Vec<Transaction>, AppError>;
+    /// 그룹 거래를 `sort` 순으로 `cursor` 다음부터 최대 `limit`건 조회합니다.
     async fn find_by_group(
         &self,
-        group_id: Option<i64>,
-        start_date: Option<NaiveDate>,
-        end_date: Option<NaiveDate>,
-        category_id: Option<i64>,
-        search: Option<&str>,
+        group_id: i64,
+        filter: &TransactionFilter,
+        sort: TransactionSort,
+        cursor: Option<&TransactionCursor>,
         limit: u32,
-        offset: u32,
     ) -> Result<Vec<Transaction>, AppError>;
+    /// 본인이 작성한 거래를 `sort` 순으로 `cursor` 다음부터 최대 `limit`건 조회합니다.
     async fn find_by_owner(
         &self,
         owner_user_id: i64,
-        start_date: Option<NaiveDate>,
-        end_date: Option<NaiveDate>,
+        filter: &TransactionFilter,
+        sort: TransactionSort,
+        cursor: Option<&TransactionCursor>,
         limit: u32,
-        offset: u32,
     ) -> Result<Vec<Transaction>, AppError>;
-    async fn update(&self, transaction: &Transaction) -> Result<Transaction, AppError>;
+    /// `links`에서 값이 있는 분할 항목, 태그도 함께 교체합니다.
+    async fn update(
+        &self,
+        transaction: &Transaction,
+        links: &TransactionLinks,
+    ) -> Result<Transaction, AppError>;
     async fn delete(&self, id: i64) -> Result<(), AppError>;
-    async fn count_by_group(
+    /// `writes`를 한 트랜잭션으로 저장하고 항목별로 저장한 거래 ID 또는 오류를 순서대로 반환합니다.
+    ///
+    /// `AllOrNothing`은 첫 실패에서 전체를 롤백하고 멈추므로 결과가 `writes`보다 짧을 수 있습니다.
+    /// `BestEffort`는 항목마다 SAVEPOINT를 두어 실패한 항목만 되돌리고 나머지는 커밋합니다.
+    async fn apply_bulk(
+        &self,
+        writes: &[BulkWrite],
+        mode: BulkMode,
+    ) -> Result<Vec<Result<i64, AppError>>, AppError>;
+    async fn count_by_group(&self, group_id: i64, filter: &TransactionFilter) -> Result<i64, AppError>;
+    async fn count_by_owner(&self, owner_user_id: i64, filter: &TransactionFilter) -> Result<i64, AppError>;
+    /// 거래들의 분할 항목을 거래별 `position` 순으로 조회합니다.
+    async fn find_splits(&self, transaction_ids: &[i64]) -> Result<Vec<TransactionSplit>, AppError>;
+    /// 거래들에 붙은 태그를 `(거래 ID, 태그)`로 조회합니다.
+    async fn find_tags(&self, transaction_ids: &[i64]) -> Result<Vec<(i64, Tag)>, AppError>;
+    /// 카테고리·유형별 합계를 구합니다. 분할된 거래는 부모 대신 분할 항목을 집계합니다.
+    ///
+    /// `rollup`이면 하위 카테고리의 합계를 상위 카테고리에 합산합니다.
+    async fn category_totals(
         &self,
-        group_id: Option<i64>,
-        start_date: Option<NaiveDate>,
-        end_date: Option<NaiveDate>,
-    ) -> Result<i64, AppError>;
+        scope: TransactionScope,
+        filter: &TransactionFilter,
+        rollup: bool,
+    ) -> Result<Vec<CategoryTotal>, AppError>;
+    /// 가맹점, 메모, 영수증 OCR 텍스트를 전문 검색해 관련도 순으로 최대 `limit`건 반환합니다.
+    ///
+    /// `terms`는 [`crate::utils::search::search_terms`]로 나눈 검색어입니다.
+    async fn search(
+        &self,
+        scope: TransactionScope,
+        terms: &[String],
+        limit: u32,
+    ) -> Result<Vec<TransactionSearchHit>, AppError>;
 }
-
@@ -5,9 +5,13 @@ use crate::errors::AppError;
 #[async_trait]
 pub trait UserRepository: Send + Sync {
     async fn create(&self, user: &User) -> Result<User, AppError>;
+    /// 초대 코드를 확인하고 해당 그룹에 소속된 사용자를 하나의 트랜잭션으로 생성합니다.
+    /// 코드가 없거나 만료·소진되었으면 사용자를 만들지 않고 Validation 에러를 반환합니다.
+    async fn create_with_invite(&self, user: &User, invite_code: &str) -> Result<User, AppError>;
     async fn find_by_id(&self, id: i64) -> Result<Option<User>, AppError>;
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, AppError>;
     async fn update(&self, user: &User) -> Result<User, AppError>;
+    async fn mark_email_verified(&self, id: i64) -> Result<(), AppError>;
     async fn delete(&self, id: i64) -> Result<(), AppError>;
     async fn find_by_group_id(&self, group_id: i64) -> Result<Vec<User>, AppError>;
 }
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use crate::domain::models::{TokenPurpose, UserToken};
+use crate::errors::AppError;
+
+#[async_trait]
+pub trait UserTokenRepository: Send + Sync {
+    async fn create(&self, token: &UserToken) -> Result<UserToken, AppError>;
+    /// 사용되지 않았고 만료되지 않은 토큰을 해시로 조회합니다.
+    async fn find_valid_by_hash(
+        &self,
+        purpose: TokenPurpose,
+        token_hash: &str,
+    ) -> Result<Option<UserToken>, AppError>;
+    /// 토큰을 사용 처리합니다. 이미 사용된 토큰이면 `false`.
+    async fn mark_used(&self, id: i64) -> Result<bool, AppError>;
+    /// 사용자의 미사용 토큰을 모두 무효화합니다.
+    async fn invalidate_for_user(&self, user_id: i64, purpose: TokenPurpose) -> Result<(), AppError>;
+}
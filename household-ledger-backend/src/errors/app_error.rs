@@ -3,8 +3,12 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
-use serde_json::json;
+use serde_json::{json, Value};
 use thiserror::Error;
+use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+use crate::errors::ErrorCode;
+use crate::i18n::MessageKey;
+use crate::utils::request_context::{current_locale, current_request_id};
 
 #[derive(Error, Debug)]
 pub enum AppError {
@@ -12,13 +16,34 @@ pub enum AppError {
     Database(#[from] sqlx::Error),
 
     #[error("Authentication error: {0}")]
-    Authentication(String),
+    Authentication(MessageKey),
 
     #[error("Validation error: {0}")]
-    Validation(String),
+    Validation(MessageKey),
+
+    /// 요청 DTO 검증 실패 (필드별 사유를 `details`로 응답)
+    #[error("Invalid input: {0}")]
+    InvalidInput(#[from] ValidationErrors),
+
+    /// 본문, 쿼리, 경로를 요청 타입으로 해석하지 못함 (해석 실패 사유를 `details`로 응답)
+    #[error("Malformed request: {key} ({reason})")]
+    Malformed { key: MessageKey, reason: String },
+
+    #[error("Forbidden: {0}")]
+    Forbidden(MessageKey),
 
     #[error("Not found: {0}")]
-    NotFound(String),
+    NotFound(MessageKey),
+
+    #[error("Conflict: {0}")]
+    Conflict(MessageKey),
+
+    /// 연결된 거래가 있어 처리할 수 없음 (연결된 거래 수를 `details`로 응답)
+    #[error("In use: {key} ({transaction_count} transactions)")]
+    InUse { key: MessageKey, transaction_count: i64 },
+
+    #[error("Too many requests: {0}")]
+    TooManyRequests(MessageKey),
 
     #[error("Configuration error: {0}")]
     Configuration(String),
@@ -33,28 +58,152 @@ pub enum AppError {
     Password(String),
 }
 
+impl AppError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            AppError::Database(e) if is_unique_violation(e) => StatusCode::CONFLICT,
+            AppError::Authentication(_) | AppError::Jwt(_) => StatusCode::UNAUTHORIZED,
+            AppError::Validation(_) | AppError::Malformed { .. } => StatusCode::BAD_REQUEST,
+            AppError::InvalidInput(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Conflict(_) | AppError::InUse { .. } => StatusCode::CONFLICT,
+            AppError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::Database(_)
+            | AppError::Configuration(_)
+            | AppError::Internal(_)
+            | AppError::Password(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            AppError::Database(e) if is_unique_violation(e) => ErrorCode::Conflict,
+            AppError::Authentication(_) => ErrorCode::Unauthorized,
+            AppError::Jwt(_) => ErrorCode::InvalidToken,
+            AppError::Validation(_) => ErrorCode::BadRequest,
+            AppError::InvalidInput(_) | AppError::Malformed { .. } => ErrorCode::ValidationFailed,
+            AppError::Forbidden(_) => ErrorCode::Forbidden,
+            AppError::NotFound(_) => ErrorCode::NotFound,
+            AppError::Conflict(_) | AppError::InUse { .. } => ErrorCode::Conflict,
+            AppError::TooManyRequests(_) => ErrorCode::TooManyRequests,
+            AppError::Database(_)
+            | AppError::Configuration(_)
+            | AppError::Internal(_)
+            | AppError::Password(_) => ErrorCode::InternalError,
+        }
+    }
+
+    /// 클라이언트에 보여줄 메시지 키 (서버 내부 오류는 상세 내용을 숨김)
+    pub fn message_key(&self) -> MessageKey {
+        match self {
+            AppError::Authentication(key)
+            | AppError::Validation(key)
+            | AppError::Forbidden(key)
+            | AppError::NotFound(key)
+            | AppError::Conflict(key)
+            | AppError::TooManyRequests(key)
+            | AppError::InUse { key, .. }
+            | AppError::Malformed { key, .. } => *key,
+            AppError::InvalidInput(_) => MessageKey::InvalidInput,
+            AppError::Jwt(_) => MessageKey::InvalidToken,
+            AppError::Database(e) if is_unique_violation(e) => MessageKey::DuplicateResource,
+            AppError::Database(_)
+            | AppError::Configuration(_)
+            | AppError::Internal(_)
+            | AppError::Password(_) => MessageKey::InternalError,
+        }
+    }
+
+    /// UNIQUE 제약 위반으로 실패한 DB 오류인지 (서비스에서 더 구체적인 메시지로 바꿀 때 사용)
+    pub fn is_unique_violation(&self) -> bool {
+        matches!(self, AppError::Database(e) if is_unique_violation(e))
+    }
+
+    /// 오류 상세 (입력 검증 실패의 필드별 사유, 요청 해석 실패 사유, 사용 중인 리소스의 연결된 거래 수, 그 외에는 `null`)
+    pub fn details(&self) -> Value {
+        match self {
+            AppError::InvalidInput(errors) => validation_details(errors),
+            AppError::Malformed { reason, .. } => json!({ "reason": reason }),
+            AppError::InUse { transaction_count, .. } => json!({ "transaction_count": transaction_count }),
+            _ => Value::Null,
+        }
+    }
+
+    /// 내부 오류 원문은 응답 대신 로그에만 남기고 요청 ID로 추적
+    pub fn log_if_server_error(&self) {
+        if self.status().is_server_error() {
+            let request_id = current_request_id();
+            tracing::error!(request_id = request_id.as_deref().unwrap_or("-"), "{}", self);
+        }
+    }
+}
+
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            AppError::Database(_) => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Database error",
-            ),
-            AppError::Authentication(msg) => (StatusCode::UNAUTHORIZED, msg),
-            AppError::Validation(msg) => (StatusCode::BAD_REQUEST, msg),
-            AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
-            AppError::Configuration(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
-            AppError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
-            AppError::Jwt(msg) => (StatusCode::UNAUTHORIZED, msg),
-            AppError::Password(msg) => (StatusCode::BAD_REQUEST, msg),
-        };
+        let status = self.status();
+        let request_id = current_request_id();
+        self.log_if_server_error();
 
         let body = Json(json!({
-            "error": error_message,
-            "message": self.to_string()
+            "code": self.code(),
+            "message": self.message_key().text(current_locale()),
+            "details": self.details(),
+            "request_id": request_id,
         }));
 
         (status, body).into_response()
     }
 }
 
+fn is_unique_violation(error: &sqlx::Error) -> bool {
+    matches!(error, sqlx::Error::Database(db) if db.is_unique_violation())
+}
+
+/// `{ "필드명": [{ "code": "length", "message": ..., "params": { "min": 8 } }] }` 형태로 변환
+///
+/// 중첩된 항목의 오류는 `splits[0].amount`처럼 경로를 이어 붙인 필드명으로 나타냅니다.
+fn validation_details(errors: &ValidationErrors) -> Value {
+    let mut fields = serde_json::Map::new();
+    collect_field_errors(errors, "", &mut fields);
+    Value::Object(fields)
+}
+
+fn collect_field_errors(errors: &ValidationErrors, prefix: &str, fields: &mut serde_json::Map<String, Value>) {
+    for (field, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", prefix, field)
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                let items = errors.iter().map(field_error_json).collect();
+                fields.insert(path, Value::Array(items));
+            }
+            ValidationErrorsKind::Struct(nested) => collect_field_errors(nested, &path, fields),
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items {
+                    collect_field_errors(nested, &format!("{}[{}]", path, index), fields);
+                }
+            }
+        }
+    }
+}
+
+fn field_error_json(error: &ValidationError) -> Value {
+    // 입력값 자체(value)는 비밀번호 등이 포함될 수 있어 제외
+    let params: serde_json::Map<String, Value> = error
+        .params
+        .iter()
+        .filter(|(key, _)| key.as_ref() != "value")
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect();
+
+    json!({
+        "code": error.code,
+        "message": error.message,
+        "params": params,
+    })
+}
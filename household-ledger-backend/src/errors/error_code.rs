@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+/// 클라이언트가 분기 처리에 사용하는 에러 코드
+///
+/// 응답 본문의 `code` 필드로 내려가며, 한 번 공개한 값은 바꾸지 않습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// 인증 정보가 없거나 올바르지 않음
+    Unauthorized,
+    /// 토큰이 만료되었거나 위조됨
+    InvalidToken,
+    /// 요청 값 형식 검증 실패 (`details`에 필드별 사유나 해석 실패 사유 포함)
+    ValidationFailed,
+    /// 형식은 맞지만 처리할 수 없는 요청
+    BadRequest,
+    /// 권한 부족
+    Forbidden,
+    NotFound,
+    /// 이미 존재하거나 현재 상태와 충돌
+    Conflict,
+    TooManyRequests,
+    InternalError,
+}
@@ -1,4 +1,5 @@
 pub mod app_error;
+pub mod error_code;
 
 pub use app_error::AppError;
-
+pub use error_code::ErrorCode;
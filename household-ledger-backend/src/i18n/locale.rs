@@ -0,0 +1,101 @@
+/// 응답 메시지와 알림에 사용하는 언어
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    Ko,
+    En,
+}
+
+impl Locale {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Locale::Ko => "ko",
+            Locale::En => "en",
+        }
+    }
+
+    /// `ko`, `en-US` 같은 언어 태그에서 지원하는 언어를 찾습니다.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.trim().split(['-', '_']).next()?;
+
+        if primary.eq_ignore_ascii_case("ko") {
+            Some(Locale::Ko)
+        } else if primary.eq_ignore_ascii_case("en") {
+            Some(Locale::En)
+        } else {
+            None
+        }
+    }
+
+    /// `Accept-Language` 헤더에서 품질값(q)이 가장 높은 지원 언어를 고릅니다.
+    ///
+    /// 예: `en-US,en;q=0.9,ko;q=0.8` → `En`
+    pub fn from_accept_language(header: &str) -> Option<Self> {
+        let mut best: Option<(Self, f32)> = None;
+
+        for entry in header.split(',') {
+            let mut parts = entry.split(';');
+            let Some(locale) = parts.next().and_then(Self::from_tag) else {
+                continue;
+            };
+
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map(|q| q.trim().parse::<f32>().unwrap_or(0.0))
+                .unwrap_or(1.0);
+
+            // 같은 품질이면 먼저 나온 언어 우선
+            if quality > 0.0 && best.is_none_or(|(_, q)| quality > q) {
+                best = Some((locale, quality));
+            }
+        }
+
+        best.map(|(locale, _)| locale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_highest_quality_supported_locale() {
+        assert_eq!(Locale::from_accept_language("en-US,en;q=0.9,ko;q=0.8"), Some(Locale::En));
+        assert_eq!(Locale::from_accept_language("en;q=0.5, ko-KR;q=0.9"), Some(Locale::Ko));
+        assert_eq!(Locale::from_accept_language("fr-FR,fr;q=0.9,en;q=0.3"), Some(Locale::En));
+    }
+
+    #[test]
+    fn keeps_first_locale_on_equal_quality() {
+        assert_eq!(Locale::from_accept_language("ko,en"), Some(Locale::Ko));
+        assert_eq!(Locale::from_accept_language("en;q=0.7,ko;q=0.7"), Some(Locale::En));
+    }
+
+    #[test]
+    fn ignores_wildcards_and_unsupported_tags() {
+        assert_eq!(Locale::from_accept_language("*"), None);
+        assert_eq!(Locale::from_accept_language("fr,*;q=0.5"), None);
+        assert_eq!(Locale::from_accept_language("*,en;q=0.1"), Some(Locale::En));
+    }
+
+    #[test]
+    fn skips_zero_or_malformed_quality() {
+        assert_eq!(Locale::from_accept_language("en;q=0,ko;q=0.1"), Some(Locale::Ko));
+        assert_eq!(Locale::from_accept_language("en;q=abc,ko;q=0.2"), Some(Locale::Ko));
+        assert_eq!(Locale::from_accept_language("en;q=0"), None);
+    }
+
+    #[test]
+    fn rejects_empty_or_garbage_header() {
+        assert_eq!(Locale::from_accept_language(""), None);
+        assert_eq!(Locale::from_accept_language(",;;,"), None);
+        assert_eq!(Locale::from_accept_language("q=1"), None);
+    }
+
+    #[test]
+    fn parses_tags_case_insensitively() {
+        assert_eq!(Locale::from_tag("EN-us"), Some(Locale::En));
+        assert_eq!(Locale::from_tag(" ko_KR "), Some(Locale::Ko));
+        assert_eq!(Locale::from_tag("english"), None);
+    }
+}
@@ -0,0 +1,481 @@
+use std::fmt;
+use super::Locale;
+
+/// 사용자에게 보여주는 메시지의 키
+///
+/// 에러 응답, 처리 결과 메시지, 메일 알림이 모두 이 카탈로그를 사용합니다.
+/// 로그에는 언어와 무관한 `as_str()` 값이 남습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageKey {
+    // 공통
+    InvalidInput,
+    MalformedBody,
+    MalformedQuery,
+    MalformedPath,
+    DuplicateResource,
+    TooManyRequests,
+    InternalError,
+
+    // 인증
+    AuthenticationRequired,
+    InvalidToken,
+    InvalidCredentials,
+    UserNotFound,
+    SessionNotFound,
+    SessionExpired,
+    RefreshTokenReused,
+    IncorrectCurrentPassword,
+    InvalidResetLink,
+    InvalidVerificationLink,
+    InvalidEmailAddress,
+    EmailAlreadyInUse,
+    EmailAlreadyVerified,
+    EmailVerificationRequired,
+
+    // 그룹
+    GroupNotFound,
+    GroupMemberNotFound,
+    InviteNotFound,
+    InvalidGroupHeader,
+    GroupScopeMismatch,
+    NoActiveGroup,
+    InvalidInviteCode,
+    InviteExpired,
+    InviteExhausted,
+    AlreadyGroupMember,
+    AlreadyGroupOwner,
+    TransferToNonMember,
+    GroupOwnerChanged,
+    OwnerMustTransferBeforeLeaving,
+    UseLeaveForSelf,
+    GroupHasMembers,
+    PermissionDenied,
+    OwnerRoleTransferOnly,
+    CannotManageEqualOrHigherRole,
+
+    // 거래 / 카테고리 / 태그
+    TransactionNotFound,
+    CategoryNotFound,
+    CategoryNotAvailable,
+    CategoryInUse,
+    CategoryNameRequired,
+    CategoryNameTaken,
+    CategoryParentInvalid,
+    CategoryDepthExceeded,
+    CategoryHasChildren,
+    CategoryMergeIntoSelf,
+    CategoryMergeScopeMismatch,
+    AmountMustBePositive,
+    InvalidCursor,
+    InvalidAmountRange,
+    InvalidDateRange,
+    DuplicateBulkItem,
+    SplitSumMismatch,
+    TagNotFound,
+    TagNameRequired,
+    TagNameTaken,
+    TagNotAvailable,
+    TagMergeScopeMismatch,
+    TagMergeIntoSelf,
+
+    // 처리 결과
+    PasswordResetRequested,
+    PasswordResetCompleted,
+    PasswordChanged,
+    VerificationEmailResent,
+    LoggedOut,
+    SessionLoggedOut,
+    GroupDeleted,
+    GroupLeft,
+    MemberRoleChanged,
+    MemberRemoved,
+    InviteRevoked,
+    TransactionDeleted,
+    CategoryDeleted,
+    TagDeleted,
+
+    // 메일 ({nickname}, {link}, {minutes}, {hours} 치환)
+    PasswordResetMailSubject,
+    PasswordResetMailBody,
+    VerificationMailSubject,
+    VerificationMailBody,
+}
+
+impl MessageKey {
+    /// 언어와 무관한 고정 식별자
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageKey::InvalidInput => "INVALID_INPUT",
+            MessageKey::MalformedBody => "MALFORMED_BODY",
+            MessageKey::MalformedQuery => "MALFORMED_QUERY",
+            MessageKey::MalformedPath => "MALFORMED_PATH",
+            MessageKey::DuplicateResource => "DUPLICATE_RESOURCE",
+            MessageKey::TooManyRequests => "TOO_MANY_REQUESTS",
+            MessageKey::InternalError => "INTERNAL_ERROR",
+            MessageKey::AuthenticationRequired => "AUTHENTICATION_REQUIRED",
+            MessageKey::InvalidToken => "INVALID_TOKEN",
+            MessageKey::InvalidCredentials => "INVALID_CREDENTIALS",
+            MessageKey::UserNotFound => "USER_NOT_FOUND",
+            MessageKey::SessionNotFound => "SESSION_NOT_FOUND",
+            MessageKey::SessionExpired => "SESSION_EXPIRED",
+            MessageKey::RefreshTokenReused => "REFRESH_TOKEN_REUSED",
+            MessageKey::IncorrectCurrentPassword => "INCORRECT_CURRENT_PASSWORD",
+            MessageKey::InvalidResetLink => "INVALID_RESET_LINK",
+            MessageKey::InvalidVerificationLink => "INVALID_VERIFICATION_LINK",
+            MessageKey::InvalidEmailAddress => "INVALID_EMAIL_ADDRESS",
+            MessageKey::EmailAlreadyInUse => "EMAIL_ALREADY_IN_USE",
+            MessageKey::EmailAlreadyVerified => "EMAIL_ALREADY_VERIFIED",
+            MessageKey::EmailVerificationRequired => "EMAIL_VERIFICATION_REQUIRED",
+            MessageKey::GroupNotFound => "GROUP_NOT_FOUND",
+            MessageKey::GroupMemberNotFound => "GROUP_MEMBER_NOT_FOUND",
+            MessageKey::InviteNotFound => "INVITE_NOT_FOUND",
+            MessageKey::InvalidGroupHeader => "INVALID_GROUP_HEADER",
+            MessageKey::GroupScopeMismatch => "GROUP_SCOPE_MISMATCH",
+            MessageKey::NoActiveGroup => "NO_ACTIVE_GROUP",
+            MessageKey::InvalidInviteCode => "INVALID_INVITE_CODE",
+            MessageKey::InviteExpired => "INVITE_EXPIRED",
+            MessageKey::InviteExhausted => "INVITE_EXHAUSTED",
+            MessageKey::AlreadyGroupMember => "ALREADY_GROUP_MEMBER",
+            MessageKey::AlreadyGroupOwner => "ALREADY_GROUP_OWNER",
+            MessageKey::TransferToNonMember => "TRANSFER_TO_NON_MEMBER",
+            MessageKey::GroupOwnerChanged => "GROUP_OWNER_CHANGED",
+            MessageKey::OwnerMustTransferBeforeLeaving => "OWNER_MUST_TRANSFER_BEFORE_LEAVING",
+            MessageKey::UseLeaveForSelf => "USE_LEAVE_FOR_SELF",
+            MessageKey::GroupHasMembers => "GROUP_HAS_MEMBERS",
+            MessageKey::PermissionDenied => "PERMISSION_DENIED",
+            MessageKey::OwnerRoleTransferOnly => "OWNER_ROLE_TRANSFER_ONLY",
+            MessageKey::CannotManageEqualOrHigherRole => "CANNOT_MANAGE_EQUAL_OR_HIGHER_ROLE",
+            MessageKey::TransactionNotFound => "TRANSACTION_NOT_FOUND",
+            MessageKey::CategoryNotFound => "CATEGORY_NOT_FOUND",
+            MessageKey::CategoryNotAvailable => "CATEGORY_NOT_AVAILABLE",
+            MessageKey::CategoryInUse => "CATEGORY_IN_USE",
+            MessageKey::CategoryNameRequired => "CATEGORY_NAME_REQUIRED",
+            MessageKey::CategoryNameTaken => "CATEGORY_NAME_TAKEN",
+            MessageKey::CategoryParentInvalid => "CATEGORY_PARENT_INVALID",
+            MessageKey::CategoryDepthExceeded => "CATEGORY_DEPTH_EXCEEDED",
+            MessageKey::CategoryHasChildren => "CATEGORY_HAS_CHILDREN",
+            MessageKey::CategoryMergeIntoSelf => "CATEGORY_MERGE_INTO_SELF",
+            MessageKey::CategoryMergeScopeMismatch => "CATEGORY_MERGE_SCOPE_MISMATCH",
+            MessageKey::AmountMustBePositive => "AMOUNT_MUST_BE_POSITIVE",
+            MessageKey::InvalidCursor => "INVALID_CURSOR",
+            MessageKey::InvalidAmountRange => "INVALID_AMOUNT_RANGE",
+            MessageKey::InvalidDateRange => "INVALID_DATE_RANGE",
+            MessageKey::DuplicateBulkItem => "DUPLICATE_BULK_ITEM",
+            MessageKey::SplitSumMismatch => "SPLIT_SUM_MISMATCH",
+            MessageKey::TagNotFound => "TAG_NOT_FOUND",
+            MessageKey::TagNameRequired => "TAG_NAME_REQUIRED",
+            MessageKey::TagNameTaken => "TAG_NAME_TAKEN",
+            MessageKey::TagNotAvailable => "TAG_NOT_AVAILABLE",
+            MessageKey::TagMergeScopeMismatch => "TAG_MERGE_SCOPE_MISMATCH",
+            MessageKey::TagMergeIntoSelf => "TAG_MERGE_INTO_SELF",
+            MessageKey::PasswordResetRequested => "PASSWORD_RESET_REQUESTED",
+            MessageKey::PasswordResetCompleted => "PASSWORD_RESET_COMPLETED",
+            MessageKey::PasswordChanged => "PASSWORD_CHANGED",
+            MessageKey::VerificationEmailResent => "VERIFICATION_EMAIL_RESENT",
+            MessageKey::LoggedOut => "LOGGED_OUT",
+            MessageKey::SessionLoggedOut => "SESSION_LOGGED_OUT",
+            MessageKey::GroupDeleted => "GROUP_DELETED",
+            MessageKey::GroupLeft => "GROUP_LEFT",
+            MessageKey::MemberRoleChanged => "MEMBER_ROLE_CHANGED",
+            MessageKey::MemberRemoved => "MEMBER_REMOVED",
+            MessageKey::InviteRevoked => "INVITE_REVOKED",
+            MessageKey::TransactionDeleted => "TRANSACTION_DELETED",
+            MessageKey::CategoryDeleted => "CATEGORY_DELETED",
+            MessageKey::TagDeleted => "TAG_DELETED",
+            MessageKey::PasswordResetMailSubject => "PASSWORD_RESET_MAIL_SUBJECT",
+            MessageKey::PasswordResetMailBody => "PASSWORD_RESET_MAIL_BODY",
+            MessageKey::VerificationMailSubject => "VERIFICATION_MAIL_SUBJECT",
+            MessageKey::VerificationMailBody => "VERIFICATION_MAIL_BODY",
+        }
+    }
+
+    pub fn text(&self, locale: Locale) -> &'static str {
+        let (ko, en) = self.translations();
+        match locale {
+            Locale::Ko => ko,
+            Locale::En => en,
+        }
+    }
+
+    /// `{이름}` 자리표시자를 값으로 치환한 메시지
+    pub fn render(&self, locale: Locale, args: &[(&str, &str)]) -> String {
+        args.iter().fold(self.text(locale).to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), value)
+        })
+    }
+
+    /// (한국어, 영어)
+    fn translations(&self) -> (&'static str, &'static str) {
+        match self {
+            MessageKey::InvalidInput => ("입력값이 올바르지 않습니다", "Some fields are invalid"),
+            MessageKey::MalformedBody => ("요청 본문을 해석할 수 없습니다", "The request body could not be parsed"),
+            MessageKey::MalformedQuery => ("쿼리 파라미터를 해석할 수 없습니다", "The query parameters could not be parsed"),
+            MessageKey::MalformedPath => ("경로 값이 올바르지 않습니다", "The path parameters are invalid"),
+            MessageKey::DuplicateResource => ("이미 존재하는 데이터입니다", "The resource already exists"),
+            MessageKey::TooManyRequests => (
+                "요청이 너무 많습니다. 잠시 후 다시 시도해 주세요",
+                "Too many requests. Please try again later",
+            ),
+            MessageKey::InternalError => ("서버 오류가 발생했습니다", "An internal server error occurred"),
+
+            MessageKey::AuthenticationRequired => ("인증이 필요합니다", "Authentication is required"),
+            MessageKey::InvalidToken => ("유효하지 않은 토큰입니다", "The token is invalid or expired"),
+            MessageKey::InvalidCredentials => (
+                "이메일 또는 비밀번호가 올바르지 않습니다",
+                "Incorrect email or password",
+            ),
+            MessageKey::UserNotFound => ("사용자를 찾을 수 없습니다", "User not found"),
+            MessageKey::SessionNotFound => ("세션을 찾을 수 없습니다", "Session not found"),
+            MessageKey::SessionExpired => (
+                "만료되었거나 로그아웃된 세션입니다",
+                "The session has expired or been logged out",
+            ),
+            MessageKey::RefreshTokenReused => (
+                "이미 사용된 refresh token입니다",
+                "The refresh token has already been used",
+            ),
+            MessageKey::IncorrectCurrentPassword => (
+                "현재 비밀번호가 올바르지 않습니다",
+                "The current password is incorrect",
+            ),
+            MessageKey::InvalidResetLink => (
+                "유효하지 않거나 만료된 재설정 링크입니다",
+                "The reset link is invalid or has expired",
+            ),
+            MessageKey::InvalidVerificationLink => (
+                "유효하지 않거나 만료된 인증 링크입니다",
+                "The verification link is invalid or has expired",
+            ),
+            MessageKey::InvalidEmailAddress => ("잘못된 이메일 주소입니다", "Invalid email address"),
+            MessageKey::EmailAlreadyInUse => ("이미 사용 중인 이메일입니다", "The email is already in use"),
+            MessageKey::EmailAlreadyVerified => ("이미 인증된 이메일입니다", "The email is already verified"),
+            MessageKey::EmailVerificationRequired => (
+                "이메일 인증 후 이용할 수 있는 기능입니다",
+                "Please verify your email to use this feature",
+            ),
+
+            MessageKey::GroupNotFound => ("그룹을 찾을 수 없습니다", "Group not found"),
+            MessageKey::GroupMemberNotFound => ("그룹 멤버를 찾을 수 없습니다", "Group member not found"),
+            MessageKey::InviteNotFound => ("초대 코드를 찾을 수 없습니다", "Invite not found"),
+            MessageKey::InvalidGroupHeader => (
+                "X-Group-Id 헤더는 그룹 ID여야 합니다",
+                "The X-Group-Id header must be a group ID",
+            ),
+            MessageKey::GroupScopeMismatch => (
+                "X-Group-Id 헤더와 요청의 group_id가 다릅니다",
+                "The X-Group-Id header and group_id in the request refer to different groups",
+            ),
+            MessageKey::NoActiveGroup => ("속한 그룹이 없습니다", "You are not in a group"),
+            MessageKey::InvalidInviteCode => ("유효하지 않은 초대 코드입니다", "Invalid invite code"),
+            MessageKey::InviteExpired => ("만료된 초대 코드입니다", "The invite code has expired"),
+            MessageKey::InviteExhausted => (
+                "사용 횟수를 모두 소진한 초대 코드입니다",
+                "The invite code has reached its usage limit",
+            ),
+            MessageKey::AlreadyGroupMember => ("이미 참여한 그룹입니다", "You are already a member of this group"),
+            MessageKey::AlreadyGroupOwner => ("이미 그룹 소유자입니다", "You already own this group"),
+            MessageKey::TransferToNonMember => (
+                "그룹 멤버에게만 소유권을 넘길 수 있습니다",
+                "Ownership can only be transferred to a group member",
+            ),
+            MessageKey::GroupOwnerChanged => (
+                "그룹 소유자가 이미 바뀌었습니다. 새로고침 후 다시 시도해 주세요",
+                "The group owner has already changed. Refresh and try again",
+            ),
+            MessageKey::OwnerMustTransferBeforeLeaving => (
+                "그룹 소유자는 소유권을 다른 멤버에게 넘긴 뒤 탈퇴할 수 있습니다",
+                "Transfer ownership to another member before leaving the group",
+            ),
+            MessageKey::UseLeaveForSelf => (
+                "본인은 그룹 탈퇴를 이용해 주세요",
+                "Use leave group to remove yourself",
+            ),
+            MessageKey::GroupHasMembers => (
+                "멤버가 있는 그룹은 삭제할 수 없습니다",
+                "A group with other members cannot be deleted",
+            ),
+            MessageKey::PermissionDenied => (
+                "이 작업을 수행할 권한이 없습니다",
+                "You do not have permission to perform this action",
+            ),
+            MessageKey::OwnerRoleTransferOnly => (
+                "소유자 역할은 소유권 이전으로만 변경할 수 있습니다",
+                "The owner role can only change through an ownership transfer",
+            ),
+            MessageKey::CannotManageEqualOrHigherRole => (
+                "자신과 같거나 높은 역할은 관리할 수 없습니다",
+                "You cannot manage members with an equal or higher role",
+            ),
+
+            MessageKey::TransactionNotFound => ("거래를 찾을 수 없습니다", "Transaction not found"),
+            MessageKey::CategoryNotFound => ("카테고리를 찾을 수 없습니다", "Category not found"),
+            MessageKey::CategoryNotAvailable => (
+                "거래와 같은 그룹(개인 거래는 본인)의 카테고리만 지정할 수 있습니다",
+                "Only categories from the transaction's group (or your personal categories) can be used",
+            ),
+            MessageKey::CategoryInUse => (
+                "거래가 연결된 카테고리는 삭제할 수 없습니다. 다른 카테고리로 옮긴 뒤 삭제해 주세요",
+                "A category with transactions cannot be deleted. Reassign them to another category first",
+            ),
+            MessageKey::CategoryNameTaken => (
+                "같은 이름과 유형의 카테고리가 이미 있습니다",
+                "A category with this name and type already exists",
+            ),
+            MessageKey::CategoryParentInvalid => (
+                "상위 카테고리는 같은 그룹(개인 카테고리는 본인)의 같은 유형 카테고리여야 합니다",
+                "The parent must be a category of the same type in the same group (or your own personal category)",
+            ),
+            MessageKey::CategoryDepthExceeded => (
+                "카테고리는 두 단계(상위 > 하위)까지만 만들 수 있습니다",
+                "Categories can only be nested two levels deep",
+            ),
+            MessageKey::CategoryHasChildren => (
+                "하위 카테고리가 있는 카테고리는 삭제할 수 없습니다",
+                "A category with sub-categories cannot be deleted",
+            ),
+            MessageKey::CategoryMergeIntoSelf => (
+                "카테고리를 자기 자신과 병합할 수 없습니다",
+                "A category cannot be merged into itself",
+            ),
+            MessageKey::CategoryMergeScopeMismatch => (
+                "같은 그룹(개인 카테고리는 본인)의 같은 유형 카테고리로만 옮길 수 있습니다",
+                "Categories can only be merged into a category of the same type in the same group (or your own personal category)",
+            ),
+            MessageKey::CategoryNameRequired => (
+                "카테고리 이름을 입력해 주세요",
+                "A category name is required",
+            ),
+            MessageKey::AmountMustBePositive => (
+                "거래 금액은 양수여야 합니다",
+                "The transaction amount must be positive",
+            ),
+            MessageKey::InvalidCursor => (
+                "유효하지 않은 페이지 커서입니다",
+                "The page cursor is invalid",
+            ),
+            MessageKey::InvalidAmountRange => (
+                "최소 금액은 최대 금액보다 클 수 없습니다",
+                "The minimum amount cannot exceed the maximum amount",
+            ),
+            MessageKey::InvalidDateRange => (
+                "시작일은 종료일보다 늦을 수 없습니다",
+                "The start date cannot be after the end date",
+            ),
+            MessageKey::DuplicateBulkItem => (
+                "같은 거래가 요청에 여러 번 포함되어 있습니다",
+                "The same transaction appears more than once in the request",
+            ),
+            MessageKey::SplitSumMismatch => (
+                "분할 항목 금액의 합은 거래 금액과 같아야 합니다",
+                "The split amounts must add up to the transaction amount",
+            ),
+            MessageKey::TagNotFound => ("태그를 찾을 수 없습니다", "Tag not found"),
+            MessageKey::TagNameRequired => ("태그 이름을 입력해 주세요", "A tag name is required"),
+            MessageKey::TagNameTaken => (
+                "같은 이름의 태그가 이미 있습니다. 합치려면 태그 병합을 사용해 주세요",
+                "A tag with this name already exists. Merge the tags instead",
+            ),
+            MessageKey::TagNotAvailable => (
+                "거래와 같은 그룹(개인 거래는 본인)의 태그만 붙일 수 있습니다",
+                "Only tags from the transaction's group (or your personal tags) can be attached",
+            ),
+            MessageKey::TagMergeScopeMismatch => (
+                "같은 그룹(또는 같은 사용자의 개인) 태그끼리만 병합할 수 있습니다",
+                "Only tags in the same group (or the same user's personal tags) can be merged",
+            ),
+            MessageKey::TagMergeIntoSelf => (
+                "태그를 자기 자신과 병합할 수 없습니다",
+                "A tag cannot be merged into itself",
+            ),
+
+            MessageKey::PasswordResetRequested => (
+                "가입된 이메일이라면 비밀번호 재설정 안내가 발송됩니다",
+                "If the email is registered, password reset instructions have been sent",
+            ),
+            MessageKey::PasswordResetCompleted => (
+                "비밀번호가 재설정되었습니다. 다시 로그인해 주세요",
+                "Your password has been reset. Please log in again",
+            ),
+            MessageKey::PasswordChanged => ("비밀번호가 변경되었습니다", "Your password has been changed"),
+            MessageKey::VerificationEmailResent => ("인증 메일을 다시 보냈습니다", "The verification email has been resent"),
+            MessageKey::LoggedOut => ("로그아웃되었습니다", "Logged out"),
+            MessageKey::SessionLoggedOut => ("세션이 로그아웃되었습니다", "The session has been logged out"),
+            MessageKey::GroupDeleted => ("그룹이 삭제되었습니다", "The group has been deleted"),
+            MessageKey::GroupLeft => ("그룹에서 탈퇴했습니다", "You have left the group"),
+            MessageKey::MemberRoleChanged => ("멤버 역할이 변경되었습니다", "The member's role has been changed"),
+            MessageKey::MemberRemoved => ("멤버를 내보냈습니다", "The member has been removed"),
+            MessageKey::InviteRevoked => ("초대 코드가 폐기되었습니다", "The invite code has been revoked"),
+            MessageKey::TransactionDeleted => ("거래가 삭제되었습니다", "The transaction has been deleted"),
+            MessageKey::CategoryDeleted => ("카테고리가 삭제되었습니다", "The category has been deleted"),
+            MessageKey::TagDeleted => ("태그가 삭제되었습니다", "The tag has been deleted"),
+
+            MessageKey::PasswordResetMailSubject => (
+                "[가계부] 비밀번호 재설정 안내",
+                "[Household Ledger] Reset your password",
+            ),
+            MessageKey::PasswordResetMailBody => (
+                "{nickname}님, 아래 링크에서 비밀번호를 재설정해 주세요.\n\n{link}\n\n\
+                 링크는 {minutes}분 동안 한 번만 사용할 수 있습니다. 요청하지 않았다면 이 메일을 무시하세요.",
+                "Hi {nickname}, use the link below to reset your password.\n\n{link}\n\n\
+                 The link can be used once within {minutes} minutes. If you did not request this, ignore this email.",
+            ),
+            MessageKey::VerificationMailSubject => (
+                "[가계부] 이메일 주소를 인증해 주세요",
+                "[Household Ledger] Verify your email address",
+            ),
+            MessageKey::VerificationMailBody => (
+                "{nickname}님, 가입을 환영합니다. 아래 링크에서 이메일 인증을 완료해 주세요.\n\n{link}\n\n\
+                 링크는 {hours}시간 동안 유효합니다.",
+                "Welcome, {nickname}! Use the link below to verify your email address.\n\n{link}\n\n\
+                 The link is valid for {hours} hours.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for MessageKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_replaces_every_placeholder() {
+        let text = MessageKey::PasswordResetMailBody.render(
+            Locale::En,
+            &[("nickname", "Jin"), ("link", "https://example.com/reset"), ("minutes", "30")],
+        );
+
+        assert!(text.starts_with("Hi Jin, "));
+        assert!(text.contains("https://example.com/reset"));
+        assert!(text.contains("within 30 minutes"));
+        assert!(!text.contains('{'));
+    }
+
+    #[test]
+    fn render_uses_requested_locale() {
+        let text = MessageKey::PasswordResetMailBody.render(Locale::Ko, &[("nickname", "진")]);
+
+        assert!(text.starts_with("진님, "));
+    }
+
+    #[test]
+    fn render_leaves_unknown_placeholders_untouched() {
+        let text = MessageKey::PasswordResetMailBody.render(Locale::En, &[("unused", "x")]);
+
+        assert!(text.contains("{nickname}"));
+        assert_eq!(text, MessageKey::PasswordResetMailBody.text(Locale::En));
+    }
+
+    #[test]
+    fn render_without_placeholders_returns_text() {
+        assert_eq!(
+            MessageKey::InvalidInput.render(Locale::En, &[("nickname", "Jin")]),
+            MessageKey::InvalidInput.text(Locale::En),
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod locale;
+pub mod messages;
+
+pub use locale::Locale;
+pub use messages::MessageKey;
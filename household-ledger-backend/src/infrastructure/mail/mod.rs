@@ -0,0 +1,25 @@
+pub mod outbox;
+pub mod smtp;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use crate::errors::AppError;
+
+pub use outbox::OutboxMailer;
+pub use smtp::SmtpMailer;
+
+/// 발송할 이메일 (본문은 텍스트)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// 이메일 발송 추상화
+///
+/// 운영 환경은 `SmtpMailer`, 로컬 개발과 테스트는 `OutboxMailer`를 사용합니다.
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, message: &EmailMessage) -> Result<(), AppError>;
+}
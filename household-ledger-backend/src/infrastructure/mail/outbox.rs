@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use std::path::PathBuf;
+use super::{EmailMessage, Mailer};
+use crate::errors::AppError;
+
+/// 메일을 실제로 보내지 않고 디렉터리에 JSON 파일로 저장하는 Mailer
+pub struct OutboxMailer {
+    dir: PathBuf,
+}
+
+impl OutboxMailer {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// 저장된 메일을 오래된 순서로 읽어옵니다.
+    pub async fn messages(&self) -> Result<Vec<EmailMessage>, AppError> {
+        let mut entries = match tokio::fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(AppError::Internal(format!("Failed to read outbox: {}", e))),
+        };
+
+        let mut paths = Vec::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to read outbox: {}", e)))?
+        {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                paths.push(path);
+            }
+        }
+        // 파일명이 타임스탬프로 시작하므로 이름순 = 발송순
+        paths.sort();
+
+        let mut messages = Vec::with_capacity(paths.len());
+        for path in paths {
+            let content = tokio::fs::read(&path)
+                .await
+                .map_err(|e| AppError::Internal(format!("Failed to read outbox: {}", e)))?;
+            let message = serde_json::from_slice(&content)
+                .map_err(|e| AppError::Internal(format!("Invalid outbox message: {}", e)))?;
+            messages.push(message);
+        }
+
+        Ok(messages)
+    }
+}
+
+#[async_trait]
+impl Mailer for OutboxMailer {
+    async fn send(&self, message: &EmailMessage) -> Result<(), AppError> {
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to create outbox: {}", e)))?;
+
+        let file_name = format!(
+            "{}-{}.json",
+            chrono::Utc::now().format("%Y%m%d%H%M%S%3f"),
+            uuid::Uuid::new_v4()
+        );
+        let content = serde_json::to_vec_pretty(message)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize email: {}", e)))?;
+
+        tokio::fs::write(self.dir.join(&file_name), content)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to write outbox: {}", e)))?;
+
+        tracing::info!(to = %message.to, subject = %message.subject, file = %file_name, "메일을 outbox에 저장했습니다");
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use lettre::message::{header::ContentType, Mailbox};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use super::{EmailMessage, Mailer};
+use crate::errors::AppError;
+use crate::i18n::MessageKey;
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    /// `starttls`가 false이면 암호화 없이 연결합니다 (로컬 MailHog 등).
+    pub fn new(
+        host: &str,
+        port: u16,
+        credentials: Option<(String, String)>,
+        from: &str,
+        starttls: bool,
+    ) -> Result<Self, AppError> {
+        let mut builder = if starttls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+                .map_err(|e| AppError::Configuration(format!("Invalid SMTP host: {}", e)))?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+        };
+        builder = builder.port(port);
+
+        if let Some((username, password)) = credentials {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        let from = from
+            .parse::<Mailbox>()
+            .map_err(|e| AppError::Configuration(format!("Invalid sender address: {}", e)))?;
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: &EmailMessage) -> Result<(), AppError> {
+        let to = message
+            .to
+            .parse::<Mailbox>()
+            .map_err(|e| {
+                tracing::warn!("잘못된 수신 주소 {}: {}", message.to, e);
+                AppError::Validation(MessageKey::InvalidEmailAddress)
+            })?;
+
+        let email = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(message.subject.clone())
+            .header(ContentType::TEXT_PLAIN)
+            .body(message.body.clone())
+            .map_err(|e| AppError::Internal(format!("Failed to build email: {}", e)))?;
+
+        self.transport
+            .send(email)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to send email: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 pub mod database;
 pub mod external;
+pub mod mail;
 pub mod repositories;
 pub mod security;
 pub mod sse;
@@ -1,8 +1,12 @@
 use async_trait::async_trait;
-use sqlx::MySqlPool;
-use crate::domain::models::{Category, TransactionType};
+use sqlx::{MySql, MySqlConnection, MySqlPool, QueryBuilder};
+use crate::domain::models::{
+    Category, CategoryMergeSummary, DefaultCategory, DefaultCategoryResult, OwnerType,
+    TransactionType,
+};
 use crate::domain::repositories::CategoryRepository;
 use crate::errors::AppError;
+use crate::i18n::{Locale, MessageKey};
 
 pub struct CategoryRepositoryImpl {
     pool: MySqlPool,
@@ -14,18 +18,135 @@ impl CategoryRepositoryImpl {
     }
 }
 
+/// 그룹(`group_id`) 또는 개인(`group_id`가 없으면 `created_by`) 범위에서 이름과 유형이 같은 카테고리를 조회합니다.
+async fn fetch_by_name(
+    conn: &mut MySqlConnection,
+    group_id: Option<i64>,
+    created_by: i64,
+    name: &str,
+    transaction_type: TransactionType,
+) -> Result<Option<Category>, AppError> {
+    let category = sqlx::query_as!(
+        Category,
+        r#"
+        SELECT id, group_id, created_by, parent_id, name, type as `type: _`, color, is_default, default_key, budget_amount, created_at, updated_at
+        FROM categories
+        WHERE name = ? AND type = ?
+          AND (group_id = ? OR (? IS NULL AND group_id IS NULL AND created_by = ?))
+        "#,
+        name,
+        transaction_type,
+        group_id,
+        group_id,
+        created_by
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    Ok(category)
+}
+
+/// 기본 카테고리 버전을 기록하는 소유자 (그룹 범위면 그룹, 개인 범위면 사용자)
+fn default_owner(group_id: Option<i64>, created_by: i64) -> (OwnerType, i64) {
+    match group_id {
+        Some(group_id) => (OwnerType::Group, group_id),
+        None => (OwnerType::User, created_by),
+    }
+}
+
+/// 트랜잭션 안에서 `category`와 범위, 이름, 유형이 같은 카테고리를 찾고 없으면 만듭니다.
+///
+/// 두 번째 값은 새로 만들었는지 여부입니다. 같은 이름을 동시에 만들어 고유 키가 충돌하면
+/// 먼저 커밋된 카테고리를 사용합니다. 간편 입력(`TransactionRepository::create_with_category`)이 사용합니다.
+pub(crate) async fn find_or_create_category(
+    conn: &mut MySqlConnection,
+    category: &Category,
+) -> Result<(Category, bool), AppError> {
+    if let Some(existing) = fetch_by_name(
+        conn,
+        category.group_id,
+        category.created_by,
+        &category.name,
+        category.transaction_type,
+    )
+    .await?
+    {
+        return Ok((existing, false));
+    }
+
+    // 조회에서 잠금을 걸지 않아야 동시 요청끼리 갭 잠금으로 교착 상태에 빠지지 않음
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO categories (group_id, created_by, parent_id, name, type, color, is_default, budget_amount)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        category.group_id,
+        category.created_by,
+        category.parent_id,
+        category.name,
+        category.transaction_type,
+        category.color,
+        category.is_default,
+        category.budget_amount
+    )
+    .execute(&mut *conn)
+    .await;
+
+    let created = match result {
+        Ok(result) => Some(result.last_insert_id() as i64),
+        Err(sqlx::Error::Database(db)) if db.is_unique_violation() => None,
+        Err(e) => return Err(e.into()),
+    };
+
+    let found = match created {
+        Some(id) => sqlx::query_as!(
+            Category,
+            r#"
+            SELECT id, group_id, created_by, parent_id, name, type as `type: _`, color, is_default, default_key, budget_amount, created_at, updated_at
+            FROM categories
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .await?,
+        // 다른 요청이 먼저 만든 경우: 잠금 읽기는 스냅샷이 아닌 최신 커밋을 읽음
+        None => sqlx::query_as!(
+            Category,
+            r#"
+            SELECT id, group_id, created_by, parent_id, name, type as `type: _`, color, is_default, default_key, budget_amount, created_at, updated_at
+            FROM categories
+            WHERE name = ? AND type = ?
+              AND (group_id = ? OR (? IS NULL AND group_id IS NULL AND created_by = ?))
+            FOR UPDATE
+            "#,
+            category.name,
+            category.transaction_type,
+            category.group_id,
+            category.group_id,
+            category.created_by
+        )
+        .fetch_optional(&mut *conn)
+        .await?,
+    };
+
+    let found = found.ok_or_else(|| AppError::NotFound(MessageKey::CategoryNotFound))?;
+    Ok((found, created.is_some()))
+}
+
 #[async_trait]
 impl CategoryRepository for CategoryRepositoryImpl {
     async fn create(&self, category: &Category) -> Result<Category, AppError> {
         let result = sqlx::query!(
             r#"
-            INSERT INTO categories (group_id, created_by, name, type, color, is_default, budget_amount)
-            VALUES (?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO categories (group_id, created_by, parent_id, name, type, color, is_default, budget_amount)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
             "#,
             category.group_id,
             category.created_by,
+            category.parent_id,
             category.name,
-            category.transaction_type as u8,
+            category.transaction_type,
             category.color,
             category.is_default,
             category.budget_amount
@@ -35,14 +156,14 @@ impl CategoryRepository for CategoryRepositoryImpl {
 
         let id = result.last_insert_id();
         self.find_by_id(id as i64).await?
-            .ok_or_else(|| AppError::NotFound(format!("Category with id {} not found", id)))
+            .ok_or_else(|| AppError::NotFound(MessageKey::CategoryNotFound))
     }
 
     async fn find_by_id(&self, id: i64) -> Result<Option<Category>, AppError> {
         let category = sqlx::query_as!(
             Category,
             r#"
-            SELECT id, group_id, created_by, name, type as `type: _`, color, is_default, budget_amount, created_at, updated_at
+            SELECT id, group_id, created_by, parent_id, name, type as `type: _`, color, is_default, default_key, budget_amount, created_at, updated_at
             FROM categories
             WHERE id = ?
             "#,
@@ -54,21 +175,62 @@ impl CategoryRepository for CategoryRepositoryImpl {
         Ok(category)
     }
 
+    async fn find_by_ids(&self, ids: &[i64]) -> Result<Vec<Category>, AppError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut builder = QueryBuilder::<MySql>::new(
+            "SELECT id, group_id, created_by, parent_id, name, type, color, is_default, default_key, budget_amount, created_at, updated_at FROM categories WHERE id IN (",
+        );
+        let mut separated = builder.separated(", ");
+        for id in ids {
+            separated.push_bind(*id);
+        }
+        builder.push(")");
+
+        let categories = builder.build_query_as::<Category>().fetch_all(&self.pool).await?;
+        Ok(categories)
+    }
+
     async fn find_by_group(
         &self,
-        group_id: Option<i64>,
+        group_id: i64,
         transaction_type: Option<TransactionType>,
     ) -> Result<Vec<Category>, AppError> {
         let categories = sqlx::query_as!(
             Category,
             r#"
-            SELECT id, group_id, created_by, name, type as `type: _`, color, is_default, budget_amount, created_at, updated_at
+            SELECT id, group_id, created_by, parent_id, name, type as `type: _`, color, is_default, default_key, budget_amount, created_at, updated_at
             FROM categories
-            WHERE (? IS NULL OR group_id = ?)
+            WHERE group_id = ?
               AND (? IS NULL OR type = ?)
             ORDER BY name
             "#,
-            group_id, group_id,
+            group_id,
+            transaction_type, transaction_type
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(categories)
+    }
+
+    async fn find_personal(
+        &self,
+        created_by: i64,
+        transaction_type: Option<TransactionType>,
+    ) -> Result<Vec<Category>, AppError> {
+        let categories = sqlx::query_as!(
+            Category,
+            r#"
+            SELECT id, group_id, created_by, parent_id, name, type as `type: _`, color, is_default, default_key, budget_amount, created_at, updated_at
+            FROM categories
+            WHERE group_id IS NULL AND created_by = ?
+              AND (? IS NULL OR type = ?)
+            ORDER BY name
+            "#,
+            created_by,
             transaction_type, transaction_type
         )
         .fetch_all(&self.pool)
@@ -81,7 +243,7 @@ impl CategoryRepository for CategoryRepositoryImpl {
         let categories = sqlx::query_as!(
             Category,
             r#"
-            SELECT id, group_id, created_by, name, type as `type: _`, color, is_default, budget_amount, created_at, updated_at
+            SELECT id, group_id, created_by, parent_id, name, type as `type: _`, color, is_default, default_key, budget_amount, created_at, updated_at
             FROM categories
             WHERE created_by = ?
             ORDER BY name
@@ -94,17 +256,29 @@ impl CategoryRepository for CategoryRepositoryImpl {
         Ok(categories)
     }
 
+    async fn find_by_name(
+        &self,
+        group_id: Option<i64>,
+        created_by: i64,
+        name: &str,
+        transaction_type: TransactionType,
+    ) -> Result<Option<Category>, AppError> {
+        let mut conn = self.pool.acquire().await?;
+        fetch_by_name(&mut conn, group_id, created_by, name, transaction_type).await
+    }
+
     async fn update(&self, category: &Category) -> Result<Category, AppError> {
         sqlx::query!(
             r#"
             UPDATE categories
-            SET group_id = ?, created_by = ?, name = ?, type = ?, color = ?, is_default = ?, budget_amount = ?
+            SET group_id = ?, created_by = ?, parent_id = ?, name = ?, type = ?, color = ?, is_default = ?, budget_amount = ?
             WHERE id = ?
             "#,
             category.group_id,
             category.created_by,
+            category.parent_id,
             category.name,
-            category.transaction_type as u8,
+            category.transaction_type,
             category.color,
             category.is_default,
             category.budget_amount,
@@ -114,7 +288,7 @@ impl CategoryRepository for CategoryRepositoryImpl {
         .await?;
 
         self.find_by_id(category.id).await?
-            .ok_or_else(|| AppError::NotFound(format!("Category with id {} not found", category.id)))
+            .ok_or_else(|| AppError::NotFound(MessageKey::CategoryNotFound))
     }
 
     async fn delete(&self, id: i64) -> Result<(), AppError> {
@@ -124,19 +298,339 @@ impl CategoryRepository for CategoryRepositoryImpl {
         Ok(())
     }
 
-    async fn has_transactions(&self, id: i64) -> Result<bool, AppError> {
+    async fn merge(
+        &self,
+        source_id: i64,
+        target_id: i64,
+        combine_budget: bool,
+    ) -> Result<CategoryMergeSummary, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        // 두 카테고리를 먼저 잠그고, 검증과 새 상위 카테고리, 예산 계산은 잠근 값으로 수행
+        let locked = sqlx::query_as!(
+            Category,
+            r#"
+            SELECT id, group_id, created_by, parent_id, name, type as `type: _`, color, is_default, default_key, budget_amount, created_at, updated_at
+            FROM categories
+            WHERE id IN (?, ?)
+            FOR UPDATE
+            "#,
+            source_id,
+            target_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        let source = locked.iter().find(|c| c.id == source_id);
+        let target = locked.iter().find(|c| c.id == target_id);
+        let (Some(source), Some(target)) = (source, target) else {
+            return Err(AppError::NotFound(MessageKey::CategoryNotFound));
+        };
+
+        let same_scope = source.group_id == target.group_id
+            && (source.group_id.is_some() || source.created_by == target.created_by);
+        if !same_scope || source.transaction_type != target.transaction_type {
+            return Err(AppError::Validation(MessageKey::CategoryMergeScopeMismatch));
+        }
+
+        // 원본의 하위 카테고리였던 대상은 원본 자리로 올라감
+        let parent_id = if target.parent_id == Some(source.id) {
+            source.parent_id
+        } else {
+            target.parent_id
+        };
+        // 원본의 하위 카테고리는 대상 아래로 옮겨지므로 대상이 최상위여야 함
+        let moved_children: i64 = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM categories WHERE parent_id = ? AND id <> ? FOR UPDATE",
+            source_id,
+            target_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if moved_children > 0 && parent_id.is_some() {
+            return Err(AppError::Validation(MessageKey::CategoryDepthExceeded));
+        }
+
+        let budget_amount = match (combine_budget, source.budget_amount) {
+            (true, Some(budget)) => Some(target.budget_amount.unwrap_or(0).saturating_add(budget)),
+            _ => target.budget_amount,
+        };
+
+        let transactions = sqlx::query!(
+            "UPDATE transactions SET category_id = ? WHERE category_id = ?",
+            target_id,
+            source_id
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        let splits = sqlx::query!(
+            "UPDATE transaction_splits SET category_id = ? WHERE category_id = ?",
+            target_id,
+            source_id
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        let recurring_rules = sqlx::query!(
+            "UPDATE recurring_rules SET category_id = ? WHERE category_id = ?",
+            target_id,
+            source_id
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        let auto_category_rules = sqlx::query!(
+            "UPDATE auto_category_rules SET category_id = ? WHERE category_id = ?",
+            target_id,
+            source_id
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        // 대상 카테고리가 원본의 하위 카테고리였다면 대상 자신은 제외 (대상의 상위는 아래에서 저장)
+        let sub_categories = sqlx::query!(
+            "UPDATE categories SET parent_id = ? WHERE parent_id = ? AND id <> ?",
+            target_id,
+            source_id,
+            target_id
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        sqlx::query!(
+            "UPDATE categories SET parent_id = ?, budget_amount = ? WHERE id = ?",
+            parent_id,
+            budget_amount,
+            target_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!("DELETE FROM categories WHERE id = ?", source_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(CategoryMergeSummary {
+            transactions,
+            splits,
+            recurring_rules,
+            auto_category_rules,
+            sub_categories,
+        })
+    }
+
+    async fn count_transactions(&self, id: i64) -> Result<i64, AppError> {
         let count: i64 = sqlx::query_scalar!(
             r#"
             SELECT COUNT(*) as count
             FROM transactions
             WHERE category_id = ?
+               OR id IN (SELECT transaction_id FROM transaction_splits WHERE category_id = ?)
             "#,
+            id,
             id
         )
         .fetch_one(&self.pool)
         .await?;
 
-        Ok(count > 0)
+        Ok(count)
     }
-}
 
+    async fn find_default_version(
+        &self,
+        group_id: Option<i64>,
+        created_by: i64,
+    ) -> Result<Option<(u32, Locale)>, AppError> {
+        let (owner_type, owner_id) = default_owner(group_id, created_by);
+
+        let row = sqlx::query!(
+            r#"
+            SELECT version, locale
+            FROM category_default_versions
+            WHERE owner_type = ? AND owner_id = ?
+            "#,
+            owner_type,
+            owner_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|row| (row.version, Locale::from_tag(&row.locale).unwrap_or_default())))
+    }
+
+    async fn apply_defaults(
+        &self,
+        group_id: Option<i64>,
+        created_by: i64,
+        defaults: &[DefaultCategory],
+        version: u32,
+        locale: Locale,
+        restore: bool,
+    ) -> Result<DefaultCategoryResult, AppError> {
+        let (owner_type, owner_id) = default_owner(group_id, created_by);
+        let mut tx = self.pool.begin().await?;
+
+        // 버전 행을 먼저 기록해 같은 범위에 대한 동시 적용을 이 행의 잠금으로 차례대로 처리
+        sqlx::query!(
+            r#"
+            INSERT INTO category_default_versions (owner_type, owner_id, version, locale)
+            VALUES (?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE version = GREATEST(version, VALUES(version)), locale = VALUES(locale)
+            "#,
+            owner_type,
+            owner_id,
+            version,
+            locale.as_str()
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let applied: u32 = sqlx::query_scalar!(
+            "SELECT version FROM category_default_versions WHERE owner_type = ? AND owner_id = ?",
+            owner_type,
+            owner_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let mut categories = sqlx::query_as!(
+            Category,
+            r#"
+            SELECT id, group_id, created_by, parent_id, name, type as `type: _`, color, is_default, default_key, budget_amount, created_at, updated_at
+            FROM categories
+            WHERE group_id = ? OR (? IS NULL AND group_id IS NULL AND created_by = ?)
+            "#,
+            group_id,
+            group_id,
+            created_by
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut result = DefaultCategoryResult { version: applied, ..Default::default() };
+
+        // 상위 항목이 먼저 나오므로 하위 항목을 처리할 때 상위 카테고리 ID를 찾을 수 있음
+        for default in defaults {
+            let name = default.name(locale);
+            // 상위 기본 카테고리가 다른 카테고리 아래로 옮겨져 있으면 두 단계를 넘으므로 최상위에 둠
+            let parent_id = default.parent_key.and_then(|parent_key| {
+                categories
+                    .iter()
+                    .find(|c| c.default_key.as_deref() == Some(parent_key) && c.parent_id.is_none())
+                    .map(|c| c.id)
+            });
+
+            let existing = categories
+                .iter()
+                .position(|c| c.default_key.as_deref() == Some(default.key));
+            if let Some(index) = existing {
+                if !restore {
+                    continue;
+                }
+
+                // 하위 카테고리가 있는 카테고리를 다른 카테고리 아래로 옮기면 두 단계를 넘으므로 위치는 유지
+                let id = categories[index].id;
+                let has_children = categories.iter().any(|c| c.parent_id == Some(id));
+                let parent_id = if parent_id.is_some() && has_children {
+                    categories[index].parent_id
+                } else {
+                    parent_id
+                };
+
+                // 같은 이름의 다른 카테고리가 있으면 고유 키 충돌로 건너뜀 (IGNORE)
+                let restored = sqlx::query!(
+                    r#"
+                    UPDATE IGNORE categories
+                    SET name = ?, color = ?, parent_id = ?, is_default = TRUE
+                    WHERE id = ?
+                    "#,
+                    name,
+                    default.color,
+                    parent_id,
+                    id
+                )
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+
+                if restored > 0 {
+                    result.restored += 1;
+                    categories[index].name = name.to_string();
+                    categories[index].parent_id = parent_id;
+                }
+                continue;
+            }
+
+            // 이름 비교는 고유 키와 같은 콜레이션(대소문자, 끝 공백 무시)을 따르도록 DB에서 조회
+            let same_name =
+                fetch_by_name(&mut tx, group_id, created_by, name, default.transaction_type).await?;
+            if let Some(category) = same_name {
+                // 다른 기본 카테고리가 이미 이 이름을 쓰고 있으면 만들 수 없으므로 건너뜀
+                if category.default_key.is_none() {
+                    sqlx::query!(
+                        "UPDATE categories SET default_key = ?, is_default = TRUE WHERE id = ?",
+                        default.key,
+                        category.id
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+                    if let Some(existing) = categories.iter_mut().find(|c| c.id == category.id) {
+                        existing.default_key = Some(default.key.to_string());
+                        existing.is_default = true;
+                    }
+                    result.linked += 1;
+                }
+                continue;
+            }
+
+            let inserted = sqlx::query!(
+                r#"
+                INSERT INTO categories (group_id, created_by, parent_id, name, type, color, is_default, default_key)
+                VALUES (?, ?, ?, ?, ?, ?, TRUE, ?)
+                "#,
+                group_id,
+                created_by,
+                parent_id,
+                name,
+                default.transaction_type,
+                default.color,
+                default.key
+            )
+            .execute(&mut *tx)
+            .await;
+            // 조회 이후 같은 이름의 카테고리가 만들어졌으면 이 항목만 건너뜀 (문장 단위로 롤백됨)
+            let inserted = match inserted {
+                Ok(inserted) => inserted,
+                Err(sqlx::Error::Database(db)) if db.is_unique_violation() => continue,
+                Err(e) => return Err(e.into()),
+            };
+            result.created += 1;
+
+            let now = chrono::Utc::now().naive_utc();
+            categories.push(Category {
+                id: inserted.last_insert_id() as i64,
+                group_id,
+                created_by,
+                parent_id,
+                name: name.to_string(),
+                transaction_type: default.transaction_type,
+                color: Some(default.color.to_string()),
+                is_default: true,
+                default_key: Some(default.key.to_string()),
+                budget_amount: None,
+                created_at: now,
+                updated_at: now,
+            });
+        }
+
+        tx.commit().await?;
+        Ok(result)
+    }
+}
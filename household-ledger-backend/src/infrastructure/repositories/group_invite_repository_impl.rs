@@ -0,0 +1,201 @@
+use async_trait::async_trait;
+use sqlx::{MySqlConnection, MySqlPool};
+use crate::domain::models::GroupInvite;
+use crate::domain::repositories::GroupInviteRepository;
+use crate::errors::AppError;
+use crate::i18n::MessageKey;
+
+pub struct GroupInviteRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl GroupInviteRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// 트랜잭션 안에서 초대 코드를 잠그고 검증한 뒤 사용 횟수를 올립니다.
+///
+/// 가입(`UserRepository::create_with_invite`)과 기존 사용자 참여(`redeem`)가 함께 사용합니다.
+pub(crate) async fn consume_invite(
+    conn: &mut MySqlConnection,
+    code: &str,
+) -> Result<GroupInvite, AppError> {
+    let invite = sqlx::query_as!(
+        GroupInvite,
+        r#"
+        SELECT id, group_id, code, created_by, max_uses, use_count, expires_at, created_at
+        FROM group_invites
+        WHERE code = ?
+        FOR UPDATE
+        "#,
+        code
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    .ok_or_else(|| AppError::Validation(MessageKey::InvalidInviteCode))?;
+
+    if invite.expires_at <= chrono::Utc::now().naive_utc() {
+        return Err(AppError::Validation(MessageKey::InviteExpired));
+    }
+    if invite.max_uses.is_some_and(|max| invite.use_count >= max) {
+        return Err(AppError::Validation(MessageKey::InviteExhausted));
+    }
+
+    sqlx::query!(
+        "UPDATE group_invites SET use_count = use_count + 1 WHERE id = ?",
+        invite.id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(GroupInvite {
+        use_count: invite.use_count + 1,
+        ..invite
+    })
+}
+
+#[async_trait]
+impl GroupInviteRepository for GroupInviteRepositoryImpl {
+    async fn create(&self, invite: &GroupInvite) -> Result<GroupInvite, AppError> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO group_invites (group_id, code, created_by, max_uses, expires_at)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+            invite.group_id,
+            invite.code,
+            invite.created_by,
+            invite.max_uses,
+            invite.expires_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        let id = result.last_insert_id();
+        self.find_by_id(id as i64).await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::InviteNotFound))
+    }
+
+    async fn find_by_id(&self, id: i64) -> Result<Option<GroupInvite>, AppError> {
+        let invite = sqlx::query_as!(
+            GroupInvite,
+            r#"
+            SELECT id, group_id, code, created_by, max_uses, use_count, expires_at, created_at
+            FROM group_invites
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(invite)
+    }
+
+    async fn find_by_code(&self, code: &str) -> Result<Option<GroupInvite>, AppError> {
+        let invite = sqlx::query_as!(
+            GroupInvite,
+            r#"
+            SELECT id, group_id, code, created_by, max_uses, use_count, expires_at, created_at
+            FROM group_invites
+            WHERE code = ?
+            "#,
+            code
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(invite)
+    }
+
+    async fn find_by_group(&self, group_id: i64) -> Result<Vec<GroupInvite>, AppError> {
+        let invites = sqlx::query_as!(
+            GroupInvite,
+            r#"
+            SELECT id, group_id, code, created_by, max_uses, use_count, expires_at, created_at
+            FROM group_invites
+            WHERE group_id = ?
+            ORDER BY created_at DESC
+            "#,
+            group_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(invites)
+    }
+
+    async fn find_active_by_group(&self, group_id: i64) -> Result<Vec<GroupInvite>, AppError> {
+        let invites = sqlx::query_as!(
+            GroupInvite,
+            r#"
+            SELECT id, group_id, code, created_by, max_uses, use_count, expires_at, created_at
+            FROM group_invites
+            WHERE group_id = ?
+              AND expires_at > CURRENT_TIMESTAMP
+              AND (max_uses IS NULL OR use_count < max_uses)
+            ORDER BY created_at DESC
+            "#,
+            group_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(invites)
+    }
+
+    async fn redeem(&self, code: &str, user_id: i64) -> Result<GroupInvite, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let invite = consume_invite(&mut tx, code).await?;
+
+        let result = sqlx::query!(
+            "INSERT IGNORE INTO group_members (group_id, user_id) VALUES (?, ?)",
+            invite.group_id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() != 1 {
+            // 트랜잭션을 커밋하지 않으므로 사용 횟수 증가도 취소됨
+            return Err(AppError::Conflict(MessageKey::AlreadyGroupMember));
+        }
+
+        // 기본 그룹이 없던 사용자는 참여한 그룹을 기본 그룹으로 사용
+        sqlx::query!(
+            "UPDATE users SET group_id = ? WHERE id = ? AND group_id IS NULL",
+            invite.group_id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(invite)
+    }
+
+    async fn delete(&self, id: i64) -> Result<(), AppError> {
+        sqlx::query!("DELETE FROM group_invites WHERE id = ?", id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_expired(&self) -> Result<u64, AppError> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM group_invites
+            WHERE expires_at <= CURRENT_TIMESTAMP
+               OR (max_uses IS NOT NULL AND use_count >= max_uses)
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use crate::domain::models::{GroupMember, GroupRole};
+use crate::domain::repositories::GroupMemberRepository;
+use crate::errors::AppError;
+
+pub struct GroupMemberRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl GroupMemberRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl GroupMemberRepository for GroupMemberRepositoryImpl {
+    async fn is_member(&self, group_id: i64, user_id: i64) -> Result<bool, AppError> {
+        let count: i64 = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as count
+            FROM group_members
+            WHERE group_id = ? AND user_id = ?
+            "#,
+            group_id,
+            user_id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count > 0)
+    }
+
+    async fn find_role(&self, group_id: i64, user_id: i64) -> Result<Option<GroupRole>, AppError> {
+        let role = sqlx::query_scalar!(
+            r#"
+            SELECT role as `role: GroupRole`
+            FROM group_members
+            WHERE group_id = ? AND user_id = ?
+            "#,
+            group_id,
+            user_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(role)
+    }
+
+    async fn find_by_group(&self, group_id: i64) -> Result<Vec<GroupMember>, AppError> {
+        let members = sqlx::query_as!(
+            GroupMember,
+            r#"
+            SELECT group_id, user_id, role as `role: _`, joined_at
+            FROM group_members
+            WHERE group_id = ?
+            ORDER BY joined_at
+            "#,
+            group_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(members)
+    }
+
+    async fn find_by_user(&self, user_id: i64) -> Result<Vec<GroupMember>, AppError> {
+        let memberships = sqlx::query_as!(
+            GroupMember,
+            r#"
+            SELECT group_id, user_id, role as `role: _`, joined_at
+            FROM group_members
+            WHERE user_id = ?
+            ORDER BY joined_at
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(memberships)
+    }
+
+    async fn update_role(&self, group_id: i64, user_id: i64, role: GroupRole) -> Result<(), AppError> {
+        sqlx::query!(
+            "UPDATE group_members SET role = ? WHERE group_id = ? AND user_id = ?",
+            role,
+            group_id,
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn remove(&self, group_id: i64, user_id: i64) -> Result<bool, AppError> {
+        let result = sqlx::query!(
+            "DELETE FROM group_members WHERE group_id = ? AND user_id = ?",
+            group_id,
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
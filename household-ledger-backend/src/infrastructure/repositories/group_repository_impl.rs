@@ -3,6 +3,7 @@ use sqlx::MySqlPool;
 use crate::domain::models::Group;
 use crate::domain::repositories::GroupRepository;
 use crate::errors::AppError;
+use crate::i18n::MessageKey;
 
 pub struct GroupRepositoryImpl {
     pool: MySqlPool,
@@ -17,6 +18,8 @@ impl GroupRepositoryImpl {
 #[async_trait]
 impl GroupRepository for GroupRepositoryImpl {
     async fn create(&self, group: &Group) -> Result<Group, AppError> {
+        let mut tx = self.pool.begin().await?;
+
         let result = sqlx::query!(
             r#"
             INSERT INTO groups (name, owner_id)
@@ -25,12 +28,22 @@ impl GroupRepository for GroupRepositoryImpl {
             group.name,
             group.owner_id
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
 
         let id = result.last_insert_id();
+        sqlx::query!(
+            "INSERT INTO group_members (group_id, user_id, role) VALUES (?, ?, 'OWNER')",
+            id,
+            group.owner_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
         self.find_by_id(id as i64).await?
-            .ok_or_else(|| AppError::NotFound(format!("Group with id {} not found", id)))
+            .ok_or_else(|| AppError::NotFound(MessageKey::GroupNotFound))
     }
 
     async fn find_by_id(&self, id: i64) -> Result<Option<Group>, AppError> {
@@ -65,6 +78,24 @@ impl GroupRepository for GroupRepositoryImpl {
         Ok(groups)
     }
 
+    async fn find_by_member(&self, user_id: i64) -> Result<Vec<Group>, AppError> {
+        let groups = sqlx::query_as!(
+            Group,
+            r#"
+            SELECT g.id, g.name, g.owner_id, g.created_at
+            FROM groups g
+            INNER JOIN group_members gm ON gm.group_id = g.id
+            WHERE gm.user_id = ?
+            ORDER BY gm.joined_at
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(groups)
+    }
+
     async fn update(&self, group: &Group) -> Result<Group, AppError> {
         sqlx::query!(
             r#"
@@ -80,13 +111,80 @@ impl GroupRepository for GroupRepositoryImpl {
         .await?;
 
         self.find_by_id(group.id).await?
-            .ok_or_else(|| AppError::NotFound(format!("Group with id {} not found", group.id)))
+            .ok_or_else(|| AppError::NotFound(MessageKey::GroupNotFound))
+    }
+
+    async fn transfer_ownership(&self, group_id: i64, from_user_id: i64, to_user_id: i64) -> Result<Group, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        // 동시에 소유권을 넘겼거나 소유자가 바뀌었으면 멤버 역할은 건드리지 않고 롤백
+        let transferred = sqlx::query!(
+            "UPDATE groups SET owner_id = ? WHERE id = ? AND owner_id = ?",
+            to_user_id,
+            group_id,
+            from_user_id
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if transferred == 0 {
+            return Err(AppError::Conflict(MessageKey::GroupOwnerChanged));
+        }
+
+        sqlx::query!(
+            "UPDATE group_members SET role = 'ADMIN' WHERE group_id = ? AND user_id = ?",
+            group_id,
+            from_user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // 확인 이후 새 소유자가 그룹을 떠났으면 롤백
+        let promoted = sqlx::query!(
+            "UPDATE group_members SET role = 'OWNER' WHERE group_id = ? AND user_id = ?",
+            group_id,
+            to_user_id
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if promoted == 0 {
+            return Err(AppError::Validation(MessageKey::TransferToNonMember));
+        }
+
+        tx.commit().await?;
+
+        self.find_by_id(group_id).await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::GroupNotFound))
     }
 
     async fn delete(&self, id: i64) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        // 그룹 행을 잠가 확인과 삭제 사이에 멤버가 참여하지 못하게 함 (멤버 추가는 외래키 확인으로 이 행을 공유 잠금)
+        let locked = sqlx::query_scalar!("SELECT id FROM groups WHERE id = ? FOR UPDATE", id)
+            .fetch_optional(&mut *tx)
+            .await?;
+        if locked.is_none() {
+            return Err(AppError::NotFound(MessageKey::GroupNotFound));
+        }
+
+        let member_count: i64 = sqlx::query_scalar!(
+            "SELECT COUNT(*) as count FROM group_members WHERE group_id = ?",
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if member_count > 1 {
+            return Err(AppError::Conflict(MessageKey::GroupHasMembers));
+        }
+
+        // 멤버십은 외래키(ON DELETE CASCADE)로 함께 삭제됨
         sqlx::query!("DELETE FROM groups WHERE id = ?", id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
+
+        tx.commit().await?;
         Ok(())
     }
 
@@ -94,7 +192,7 @@ impl GroupRepository for GroupRepositoryImpl {
         let count: i64 = sqlx::query_scalar!(
             r#"
             SELECT COUNT(*) as count
-            FROM users
+            FROM group_members
             WHERE group_id = ?
             "#,
             id
@@ -104,5 +202,19 @@ impl GroupRepository for GroupRepositoryImpl {
 
         Ok(count > 0)
     }
-}
 
+    async fn count_members(&self, id: i64) -> Result<i64, AppError> {
+        let count: i64 = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as count
+            FROM group_members
+            WHERE group_id = ?
+            "#,
+            id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+}
@@ -1,17 +1,25 @@
 pub mod category_repository_impl;
+pub mod group_invite_repository_impl;
+pub mod group_member_repository_impl;
 pub mod group_repository_impl;
+pub mod session_repository_impl;
+pub mod tag_repository_impl;
 pub mod transaction_repository_impl;
 pub mod user_repository_impl;
+pub mod user_token_repository_impl;
 
 // 나머지 Repository 구현체는 필요시 추가
-// - group_invite_repository_impl
-// - tag_repository_impl
 // - budget_repository_impl
 // - recurring_rule_repository_impl
 // - exchange_rate_repository_impl
 // - receipt_repository_impl
 
 pub use category_repository_impl::CategoryRepositoryImpl;
+pub use group_invite_repository_impl::GroupInviteRepositoryImpl;
+pub use group_member_repository_impl::GroupMemberRepositoryImpl;
 pub use group_repository_impl::GroupRepositoryImpl;
+pub use session_repository_impl::SessionRepositoryImpl;
+pub use tag_repository_impl::TagRepositoryImpl;
 pub use transaction_repository_impl::TransactionRepositoryImpl;
 pub use user_repository_impl::UserRepositoryImpl;
+pub use user_token_repository_impl::UserTokenRepositoryImpl;
@@ -0,0 +1,139 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use sqlx::MySqlPool;
+use crate::domain::models::{DeviceInfo, Session};
+use crate::domain::repositories::SessionRepository;
+use crate::errors::AppError;
+use crate::i18n::MessageKey;
+
+pub struct SessionRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl SessionRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SessionRepository for SessionRepositoryImpl {
+    async fn create(&self, session: &Session) -> Result<Session, AppError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO auth_sessions (id, user_id, refresh_jti, user_agent, ip_address, expires_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+            session.id,
+            session.user_id,
+            session.refresh_jti,
+            session.user_agent,
+            session.ip_address,
+            session.expires_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        self.find_by_id(&session.id).await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::SessionNotFound))
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<Session>, AppError> {
+        let session = sqlx::query_as!(
+            Session,
+            r#"
+            SELECT id, user_id, refresh_jti, user_agent, ip_address, created_at, last_used_at, expires_at, revoked_at
+            FROM auth_sessions
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(session)
+    }
+
+    async fn find_active_by_user(&self, user_id: i64) -> Result<Vec<Session>, AppError> {
+        let sessions = sqlx::query_as!(
+            Session,
+            r#"
+            SELECT id, user_id, refresh_jti, user_agent, ip_address, created_at, last_used_at, expires_at, revoked_at
+            FROM auth_sessions
+            WHERE user_id = ?
+              AND revoked_at IS NULL
+              AND expires_at > CURRENT_TIMESTAMP
+            ORDER BY last_used_at DESC
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(sessions)
+    }
+
+    async fn rotate(
+        &self,
+        id: &str,
+        current_jti: &str,
+        new_jti: &str,
+        expires_at: NaiveDateTime,
+        device: &DeviceInfo,
+    ) -> Result<bool, AppError> {
+        // 조건부 UPDATE로 동시에 들어온 같은 토큰 중 하나만 성공하도록 보장
+        let result = sqlx::query!(
+            r#"
+            UPDATE auth_sessions
+            SET refresh_jti = ?, expires_at = ?, last_used_at = CURRENT_TIMESTAMP,
+                user_agent = COALESCE(?, user_agent), ip_address = COALESCE(?, ip_address)
+            WHERE id = ?
+              AND refresh_jti = ?
+              AND revoked_at IS NULL
+              AND expires_at > CURRENT_TIMESTAMP
+            "#,
+            new_jti,
+            expires_at,
+            device.user_agent,
+            device.ip_address,
+            id,
+            current_jti
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn revoke(&self, id: &str) -> Result<(), AppError> {
+        sqlx::query!(
+            r#"
+            UPDATE auth_sessions
+            SET revoked_at = CURRENT_TIMESTAMP
+            WHERE id = ? AND revoked_at IS NULL
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(&self, user_id: i64, except: Option<&str>) -> Result<u64, AppError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE auth_sessions
+            SET revoked_at = CURRENT_TIMESTAMP
+            WHERE user_id = ?
+              AND revoked_at IS NULL
+              AND (? IS NULL OR id <> ?)
+            "#,
+            user_id,
+            except, except
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
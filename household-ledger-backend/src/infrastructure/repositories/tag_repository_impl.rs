@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use sqlx::{MySql, MySqlPool, QueryBuilder};
+use crate::domain::models::Tag;
+use crate::domain::repositories::TagRepository;
+use crate::errors::AppError;
+use crate::i18n::MessageKey;
+
+pub struct TagRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl TagRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TagRepository for TagRepositoryImpl {
+    async fn create(&self, tag: &Tag) -> Result<Tag, AppError> {
+        let result = sqlx::query!(
+            "INSERT INTO tags (group_id, created_by, name) VALUES (?, ?, ?)",
+            tag.group_id,
+            tag.created_by,
+            tag.name
+        )
+        .execute(&self.pool)
+        .await?;
+
+        let id = result.last_insert_id();
+        self.find_by_id(id as i64).await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::TagNotFound))
+    }
+
+    async fn find_by_id(&self, id: i64) -> Result<Option<Tag>, AppError> {
+        let tag = sqlx::query_as!(
+            Tag,
+            r#"
+            SELECT id, group_id, created_by, name, created_at, updated_at
+            FROM tags
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(tag)
+    }
+
+    async fn find_by_ids(&self, ids: &[i64]) -> Result<Vec<Tag>, AppError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut builder = QueryBuilder::<MySql>::new(
+            "SELECT id, group_id, created_by, name, created_at, updated_at FROM tags WHERE id IN (",
+        );
+        let mut separated = builder.separated(", ");
+        for id in ids {
+            separated.push_bind(*id);
+        }
+        builder.push(")");
+
+        let tags = builder.build_query_as::<Tag>().fetch_all(&self.pool).await?;
+        Ok(tags)
+    }
+
+    async fn find_by_group(&self, group_id: i64) -> Result<Vec<Tag>, AppError> {
+        let tags = sqlx::query_as!(
+            Tag,
+            r#"
+            SELECT id, group_id, created_by, name, created_at, updated_at
+            FROM tags
+            WHERE group_id = ?
+            ORDER BY name
+            "#,
+            group_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tags)
+    }
+
+    async fn find_personal(&self, created_by: i64) -> Result<Vec<Tag>, AppError> {
+        let tags = sqlx::query_as!(
+            Tag,
+            r#"
+            SELECT id, group_id, created_by, name, created_at, updated_at
+            FROM tags
+            WHERE group_id IS NULL AND created_by = ?
+            ORDER BY name
+            "#,
+            created_by
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tags)
+    }
+
+    async fn find_by_creator(&self, created_by: i64) -> Result<Vec<Tag>, AppError> {
+        let tags = sqlx::query_as!(
+            Tag,
+            r#"
+            SELECT id, group_id, created_by, name, created_at, updated_at
+            FROM tags
+            WHERE created_by = ?
+            ORDER BY name
+            "#,
+            created_by
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tags)
+    }
+
+    async fn update(&self, tag: &Tag) -> Result<Tag, AppError> {
+        sqlx::query!(
+            "UPDATE tags SET name = ? WHERE id = ?",
+            tag.name,
+            tag.id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        self.find_by_id(tag.id).await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::TagNotFound))
+    }
+
+    async fn delete(&self, id: i64) -> Result<(), AppError> {
+        sqlx::query!("DELETE FROM tags WHERE id = ?", id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn merge(&self, source_id: i64, target_id: i64) -> Result<u64, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let moved: i64 = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM transaction_tags WHERE tag_id = ?",
+            source_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        // 두 태그가 모두 붙은 거래는 기본 키가 겹치므로 건너뜀 (원본 연결은 태그 삭제 시 함께 삭제)
+        sqlx::query!(
+            r#"
+            INSERT IGNORE INTO transaction_tags (transaction_id, tag_id)
+            SELECT transaction_id, ?
+            FROM transaction_tags
+            WHERE tag_id = ?
+            "#,
+            target_id,
+            source_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!("DELETE FROM tags WHERE id = ?", source_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(moved as u64)
+    }
+}
@@ -1,9 +1,16 @@
 use async_trait::async_trait;
-use chrono::NaiveDate;
-use sqlx::MySqlPool;
-use crate::domain::models::Transaction;
+use sqlx::mysql::MySqlRow;
+use sqlx::{Acquire, FromRow, MySql, MySqlConnection, MySqlPool, QueryBuilder, Row};
+use crate::domain::models::{
+    BulkMode, BulkWrite, Category, CategoryTotal, QuickAddedTransaction, SortDirection, Tag,
+    Transaction, TransactionCursor, TransactionFilter, TransactionLinks, TransactionPatch,
+    TransactionScope, TransactionSearchHit, TransactionSort, TransactionSortField, TransactionSplit,
+};
 use crate::domain::repositories::TransactionRepository;
 use crate::errors::AppError;
+use crate::i18n::MessageKey;
+use crate::utils::search::search_terms;
+use super::category_repository_impl::find_or_create_category;
 
 pub struct TransactionRepositoryImpl {
     pool: MySqlPool,
@@ -15,37 +22,257 @@ impl TransactionRepositoryImpl {
     }
 }
 
-#[async_trait]
-impl TransactionRepository for TransactionRepositoryImpl {
-    async fn create(&self, transaction: &Transaction) -> Result<Transaction, AppError> {
-        let result = sqlx::query!(
-            r#"
-            INSERT INTO transactions (
-                group_id, owner_user_id, type, date, amount, currency_code, original_amount,
-                category_id, tag_id, recurring_rule_id, receipt_id, merchant, memo
-            )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-            "#,
-            transaction.group_id,
-            transaction.owner_user_id,
-            transaction.transaction_type,
-            transaction.date,
-            transaction.amount,
-            transaction.currency_code,
-            transaction.original_amount,
-            transaction.category_id,
-            transaction.tag_id,
-            transaction.recurring_rule_id,
-            transaction.receipt_id,
-            transaction.merchant,
-            transaction.memo
+/// 거래 한 건을 저장하고 ID를 반환합니다.
+async fn insert_transaction(conn: &mut MySqlConnection, transaction: &Transaction) -> Result<i64, AppError> {
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO transactions (
+            group_id, owner_user_id, type, date, amount, currency_code, original_amount,
+            category_id, recurring_rule_id, receipt_id, merchant, memo
         )
-        .execute(&self.pool)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        transaction.group_id,
+        transaction.owner_user_id,
+        transaction.transaction_type,
+        transaction.date,
+        transaction.amount,
+        transaction.currency_code,
+        transaction.original_amount,
+        transaction.category_id,
+        transaction.recurring_rule_id,
+        transaction.receipt_id,
+        transaction.merchant,
+        transaction.memo
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(result.last_insert_id() as i64)
+}
+
+async fn update_transaction(conn: &mut MySqlConnection, transaction: &Transaction) -> Result<(), AppError> {
+    sqlx::query!(
+        r#"
+        UPDATE transactions
+        SET group_id = ?, owner_user_id = ?, type = ?, date = ?, amount = ?,
+            currency_code = ?, original_amount = ?, category_id = ?,
+            recurring_rule_id = ?, receipt_id = ?, merchant = ?, memo = ?
+        WHERE id = ?
+        "#,
+        transaction.group_id,
+        transaction.owner_user_id,
+        transaction.transaction_type,
+        transaction.date,
+        transaction.amount,
+        transaction.currency_code,
+        transaction.original_amount,
+        transaction.category_id,
+        transaction.recurring_rule_id,
+        transaction.receipt_id,
+        transaction.merchant,
+        transaction.memo,
+        transaction.id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// `patch`에서 값이 있는 필드만 바꿉니다. 바꿀 필드가 없으면 아무것도 하지 않습니다.
+async fn update_fields(conn: &mut MySqlConnection, id: i64, patch: &TransactionPatch) -> Result<(), AppError> {
+    let mut builder = QueryBuilder::<MySql>::new("UPDATE transactions SET ");
+    let mut fields = builder.separated(", ");
+    let mut changed = false;
+
+    if let Some(group_id) = patch.group_id {
+        fields.push("group_id = ").push_bind_unseparated(group_id);
+        changed = true;
+    }
+    if let Some(amount) = patch.amount {
+        fields.push("amount = ").push_bind_unseparated(amount);
+        changed = true;
+    }
+    if let Some(currency_code) = &patch.currency_code {
+        fields.push("currency_code = ").push_bind_unseparated(currency_code.clone());
+        changed = true;
+    }
+    if let Some(original_amount) = patch.original_amount {
+        fields.push("original_amount = ").push_bind_unseparated(original_amount);
+        changed = true;
+    }
+    if let Some(category_id) = patch.category_id {
+        fields.push("category_id = ").push_bind_unseparated(category_id);
+        changed = true;
+    }
+    if let Some(merchant) = &patch.merchant {
+        fields.push("merchant = ").push_bind_unseparated(merchant.clone());
+        changed = true;
+    }
+    if let Some(memo) = &patch.memo {
+        fields.push("memo = ").push_bind_unseparated(memo.clone());
+        changed = true;
+    }
+    if let Some(transaction_type) = patch.transaction_type {
+        fields.push("type = ").push_bind_unseparated(transaction_type);
+        changed = true;
+    }
+    if let Some(date) = patch.date {
+        fields.push("date = ").push_bind_unseparated(date);
+        changed = true;
+    }
+
+    if !changed {
+        return Ok(());
+    }
+
+    builder.push(" WHERE id = ").push_bind(id);
+    builder.build().execute(&mut *conn).await?;
+
+    Ok(())
+}
+
+/// 삭제한 행 수를 반환합니다.
+async fn delete_transaction(conn: &mut MySqlConnection, id: i64) -> Result<u64, AppError> {
+    let result = sqlx::query!("DELETE FROM transactions WHERE id = ?", id)
+        .execute(&mut *conn)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+/// 거래의 분할 항목을 `splits`로 모두 바꿉니다. 빈 목록이면 분할을 해제합니다.
+async fn replace_splits(
+    conn: &mut MySqlConnection,
+    transaction_id: i64,
+    splits: &[TransactionSplit],
+) -> Result<(), AppError> {
+    sqlx::query!("DELETE FROM transaction_splits WHERE transaction_id = ?", transaction_id)
+        .execute(&mut *conn)
         .await?;
 
-        let id = result.last_insert_id();
-        self.find_by_id(id as i64).await?
-            .ok_or_else(|| AppError::NotFound(format!("Transaction with id {} not found", id)))
+    if splits.is_empty() {
+        return Ok(());
+    }
+
+    let mut builder = QueryBuilder::<MySql>::new(
+        "INSERT INTO transaction_splits (transaction_id, category_id, amount, memo, position) ",
+    );
+    builder.push_values(splits.iter().enumerate(), |mut row, (position, split)| {
+        row.push_bind(transaction_id)
+            .push_bind(split.category_id)
+            .push_bind(split.amount)
+            .push_bind(split.memo.clone())
+            .push_bind(position as i32);
+    });
+    builder.build().execute(&mut *conn).await?;
+
+    Ok(())
+}
+
+/// 거래의 태그를 `tag_ids`로 모두 바꿉니다. 빈 목록이면 태그를 모두 뗍니다.
+async fn replace_tags(
+    conn: &mut MySqlConnection,
+    transaction_id: i64,
+    tag_ids: &[i64],
+) -> Result<(), AppError> {
+    sqlx::query!("DELETE FROM transaction_tags WHERE transaction_id = ?", transaction_id)
+        .execute(&mut *conn)
+        .await?;
+
+    if tag_ids.is_empty() {
+        return Ok(());
+    }
+
+    let mut builder = QueryBuilder::<MySql>::new("INSERT IGNORE INTO transaction_tags (transaction_id, tag_id) ");
+    builder.push_values(tag_ids, |mut row, tag_id| {
+        row.push_bind(transaction_id).push_bind(*tag_id);
+    });
+    builder.build().execute(&mut *conn).await?;
+
+    Ok(())
+}
+
+/// `links`에서 값이 있는 항목(분할 항목, 태그)만 교체합니다.
+async fn save_links(
+    conn: &mut MySqlConnection,
+    transaction_id: i64,
+    links: &TransactionLinks,
+) -> Result<(), AppError> {
+    if let Some(splits) = &links.splits {
+        replace_splits(conn, transaction_id, splits).await?;
+    }
+    if let Some(tag_ids) = &links.tag_ids {
+        replace_tags(conn, transaction_id, tag_ids).await?;
+    }
+    Ok(())
+}
+
+/// 일괄 처리 한 건을 실행하고 대상 거래 ID를 반환합니다.
+async fn apply_write(conn: &mut MySqlConnection, write: &BulkWrite) -> Result<i64, AppError> {
+    match write {
+        BulkWrite::Create { transaction, links } => {
+            let id = insert_transaction(conn, transaction).await?;
+            save_links(conn, id, links).await?;
+            Ok(id)
+        }
+        BulkWrite::Update { id, patch } => {
+            update_fields(conn, *id, patch).await?;
+            save_links(conn, *id, &patch.links()).await?;
+            Ok(*id)
+        }
+        BulkWrite::Delete(id) => {
+            // 검증 이후 다른 요청이 먼저 삭제한 경우
+            if delete_transaction(conn, *id).await? == 0 {
+                return Err(AppError::NotFound(MessageKey::TransactionNotFound));
+            }
+            Ok(*id)
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionRepository for TransactionRepositoryImpl {
+    async fn create(
+        &self,
+        transaction: &Transaction,
+        links: &TransactionLinks,
+    ) -> Result<Transaction, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let id = insert_transaction(&mut tx, transaction).await?;
+        save_links(&mut tx, id, links).await?;
+        tx.commit().await?;
+
+        self.find_by_id(id).await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::TransactionNotFound))
+    }
+
+    async fn create_with_category(
+        &self,
+        transaction: &Transaction,
+        category: &Category,
+        tag_ids: &[i64],
+    ) -> Result<QuickAddedTransaction, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let (category, category_created) = find_or_create_category(&mut tx, category).await?;
+        let transaction = Transaction {
+            category_id: Some(category.id),
+            ..transaction.clone()
+        };
+        let id = insert_transaction(&mut tx, &transaction).await?;
+        replace_tags(&mut tx, id, tag_ids).await?;
+
+        tx.commit().await?;
+
+        let transaction = self.find_by_id(id).await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::TransactionNotFound))?;
+
+        Ok(QuickAddedTransaction {
+            transaction,
+            category,
+            category_created,
+        })
     }
 
     async fn find_by_id(&self, id: i64) -> Result<Option<Transaction>, AppError> {
@@ -54,7 +281,7 @@ impl TransactionRepository for TransactionRepositoryImpl {
             r#"
             SELECT 
                 id, group_id, owner_user_id, type as `type: _`, date, amount, currency_code,
-                original_amount, category_id, tag_id, recurring_rule_id, receipt_id,
+                original_amount, category_id, recurring_rule_id, receipt_id,
                 merchant, memo, created_at, updated_at
             FROM transactions
             WHERE id = ?
@@ -67,202 +294,540 @@ impl TransactionRepository for TransactionRepositoryImpl {
         Ok(transaction)
     }
 
+    async fn find_by_ids(&self, ids: &[i64]) -> Result<Vec<Transaction>, AppError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut builder = QueryBuilder::<MySql>::new(SELECT_TRANSACTIONS);
+        builder.push(" WHERE id IN (");
+        let mut separated = builder.separated(", ");
+        for id in ids {
+            separated.push_bind(*id);
+        }
+        builder.push(")");
+
+        let rows = builder.build().fetch_all(&self.pool).await?;
+        let transactions = rows
+            .iter()
+            .map(transaction_from_row)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(transactions)
+    }
+
     async fn find_by_group(
         &self,
-        group_id: Option<i64>,
-        start_date: Option<NaiveDate>,
-        end_date: Option<NaiveDate>,
-        category_id: Option<i64>,
-        search: Option<&str>,
+        group_id: i64,
+        filter: &TransactionFilter,
+        sort: TransactionSort,
+        cursor: Option<&TransactionCursor>,
         limit: u32,
-        offset: u32,
     ) -> Result<Vec<Transaction>, AppError> {
-        let mut query = String::from(
-            r#"
-            SELECT 
-                id, group_id, owner_user_id, type as `type: _`, date, amount, currency_code,
-                original_amount, category_id, tag_id, recurring_rule_id, receipt_id,
-                merchant, memo, created_at, updated_at
-            FROM transactions
-            WHERE 1=1
-            "#
-        );
+        let mut builder = QueryBuilder::<MySql>::new(SELECT_TRANSACTIONS);
+        builder.push(" WHERE group_id = ").push_bind(group_id);
+        self.fetch_page(builder, filter, sort, cursor, limit).await
+    }
+
+    async fn find_by_owner(
+        &self,
+        owner_user_id: i64,
+        filter: &TransactionFilter,
+        sort: TransactionSort,
+        cursor: Option<&TransactionCursor>,
+        limit: u32,
+    ) -> Result<Vec<Transaction>, AppError> {
+        let mut builder = QueryBuilder::<MySql>::new(SELECT_TRANSACTIONS);
+        builder.push(" WHERE owner_user_id = ").push_bind(owner_user_id);
+        self.fetch_page(builder, filter, sort, cursor, limit).await
+    }
+
+    async fn update(
+        &self,
+        transaction: &Transaction,
+        links: &TransactionLinks,
+    ) -> Result<Transaction, AppError> {
+        let mut tx = self.pool.begin().await?;
+        update_transaction(&mut tx, transaction).await?;
+        save_links(&mut tx, transaction.id, links).await?;
+        tx.commit().await?;
+
+        self.find_by_id(transaction.id).await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::TransactionNotFound))
+    }
 
-        let mut params: Vec<Box<dyn sqlx::Encode<'_, sqlx::MySql> + Send + Sync>> = vec![];
+    async fn delete(&self, id: i64) -> Result<(), AppError> {
+        delete_transaction(&mut *self.pool.acquire().await?, id).await?;
+        Ok(())
+    }
+
+    async fn apply_bulk(
+        &self,
+        writes: &[BulkWrite],
+        mode: BulkMode,
+    ) -> Result<Vec<Result<i64, AppError>>, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let mut results = Vec::with_capacity(writes.len());
+
+        for write in writes {
+            let result = match mode {
+                BulkMode::AllOrNothing => apply_write(&mut tx, write).await,
+                BulkMode::BestEffort => {
+                    // 실패한 항목만 되돌리도록 항목마다 SAVEPOINT 사용
+                    let mut savepoint = tx.begin().await?;
+                    let result = apply_write(&mut savepoint, write).await;
+                    if result.is_ok() {
+                        savepoint.commit().await?;
+                    } else {
+                        savepoint.rollback().await?;
+                    }
+                    result
+                }
+            };
+
+            let failed = result.is_err();
+            results.push(result);
 
-        if let Some(gid) = group_id {
-            query.push_str(" AND group_id = ?");
-            params.push(Box::new(gid));
+            if failed && mode == BulkMode::AllOrNothing {
+                tx.rollback().await?;
+                return Ok(results);
+            }
         }
 
-        if let Some(sd) = start_date {
-            query.push_str(" AND date >= ?");
-            params.push(Box::new(sd));
+        tx.commit().await?;
+        Ok(results)
+    }
+
+    async fn count_by_group(&self, group_id: i64, filter: &TransactionFilter) -> Result<i64, AppError> {
+        let mut builder = QueryBuilder::<MySql>::new("SELECT COUNT(*) FROM transactions");
+        builder.push(" WHERE group_id = ").push_bind(group_id);
+        push_filter(&mut builder, filter);
+
+        let count: i64 = builder.build_query_scalar().fetch_one(&self.pool).await?;
+        Ok(count)
+    }
+
+    async fn count_by_owner(&self, owner_user_id: i64, filter: &TransactionFilter) -> Result<i64, AppError> {
+        let mut builder = QueryBuilder::<MySql>::new("SELECT COUNT(*) FROM transactions");
+        builder.push(" WHERE owner_user_id = ").push_bind(owner_user_id);
+        push_filter(&mut builder, filter);
+
+        let count: i64 = builder.build_query_scalar().fetch_one(&self.pool).await?;
+        Ok(count)
+    }
+
+    async fn find_splits(&self, transaction_ids: &[i64]) -> Result<Vec<TransactionSplit>, AppError> {
+        if transaction_ids.is_empty() {
+            return Ok(Vec::new());
         }
 
-        if let Some(ed) = end_date {
-            query.push_str(" AND date <= ?");
-            params.push(Box::new(ed));
+        let mut builder = QueryBuilder::<MySql>::new(
+            r#"
+            SELECT id, transaction_id, category_id, amount, memo, position, created_at, updated_at
+            FROM transaction_splits
+            WHERE transaction_id IN ("#,
+        );
+        let mut ids = builder.separated(", ");
+        for transaction_id in transaction_ids {
+            ids.push_bind(*transaction_id);
         }
+        builder.push(") ORDER BY transaction_id, position");
 
-        if let Some(cid) = category_id {
-            query.push_str(" AND category_id = ?");
-            params.push(Box::new(cid));
+        let splits = builder
+            .build_query_as::<TransactionSplit>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(splits)
+    }
+
+    async fn find_tags(&self, transaction_ids: &[i64]) -> Result<Vec<(i64, Tag)>, AppError> {
+        if transaction_ids.is_empty() {
+            return Ok(Vec::new());
         }
 
-        if let Some(s) = search {
-            query.push_str(" AND (merchant LIKE ? OR memo LIKE ?)");
-            let search_pattern = format!("%{}%", s);
-            params.push(Box::new(search_pattern.clone()));
-            params.push(Box::new(search_pattern));
+        let mut builder = QueryBuilder::<MySql>::new(
+            r#"
+            SELECT tt.transaction_id, t.id, t.group_id, t.created_by, t.name, t.created_at, t.updated_at
+            FROM transaction_tags tt
+            INNER JOIN tags t ON t.id = tt.tag_id
+            WHERE tt.transaction_id IN ("#,
+        );
+        let mut ids = builder.separated(", ");
+        for transaction_id in transaction_ids {
+            ids.push_bind(*transaction_id);
         }
+        builder.push(") ORDER BY tt.transaction_id, t.name");
 
-        query.push_str(" ORDER BY date DESC, created_at DESC LIMIT ? OFFSET ?");
-        params.push(Box::new(limit));
-        params.push(Box::new(offset));
-
-        // SQLx의 동적 쿼리는 복잡하므로, 간단한 방법으로 구현
-        let transactions = if group_id.is_some() || start_date.is_some() || end_date.is_some() || category_id.is_some() || search.is_some() {
-            // 복잡한 쿼리는 나중에 최적화
-            sqlx::query_as!(
-                Transaction,
-                r#"
-                SELECT 
-                    id, group_id, owner_user_id, type as `type: _`, date, amount, currency_code,
-                    original_amount, category_id, tag_id, recurring_rule_id, receipt_id,
-                    merchant, memo, created_at, updated_at
-                FROM transactions
-                WHERE (? IS NULL OR group_id = ?)
-                  AND (? IS NULL OR date >= ?)
-                  AND (? IS NULL OR date <= ?)
-                  AND (? IS NULL OR category_id = ?)
-                  AND (? IS NULL OR merchant LIKE ? OR memo LIKE ?)
-                ORDER BY date DESC, created_at DESC
-                LIMIT ? OFFSET ?
-                "#,
-                group_id, group_id,
-                start_date, start_date,
-                end_date, end_date,
-                category_id, category_id,
-                search, search, search,
-                limit, offset
+        let rows = builder.build().fetch_all(&self.pool).await?;
+        let tags = rows
+            .iter()
+            .map(|row| Ok((row.try_get("transaction_id")?, Tag::from_row(row)?)))
+            .collect::<Result<Vec<_>, sqlx::Error>>()?;
+
+        Ok(tags)
+    }
+
+    async fn category_totals(
+        &self,
+        scope: TransactionScope,
+        filter: &TransactionFilter,
+        rollup: bool,
+    ) -> Result<Vec<CategoryTotal>, AppError> {
+        // 하위 카테고리를 합산하면 상위 카테고리로 묶고, 상위 카테고리 예산이 없으면 하위 카테고리 예산의 합을 사용
+        let (category, budget) = if rollup {
+            (
+                "COALESCE(leaf.parent_id, leaf.id)",
+                "COALESCE(c.budget_amount, CAST((SELECT SUM(sub.budget_amount) FROM categories sub WHERE sub.parent_id = c.id) AS SIGNED))",
             )
-            .fetch_all(&self.pool)
-            .await?
         } else {
-            sqlx::query_as!(
-                Transaction,
-                r#"
-                SELECT 
-                    id, group_id, owner_user_id, type as `type: _`, date, amount, currency_code,
-                    original_amount, category_id, tag_id, recurring_rule_id, receipt_id,
-                    merchant, memo, created_at, updated_at
-                FROM transactions
-                ORDER BY date DESC, created_at DESC
-                LIMIT ? OFFSET ?
-                "#,
-                limit, offset
-            )
-            .fetch_all(&self.pool)
-            .await?
+            ("leaf.id", "c.budget_amount")
         };
 
-        Ok(transactions)
+        // 분할 항목이 있는 거래는 분할 항목마다, 없는 거래는 거래 자체를 한 줄로 펼친 뒤 집계
+        let mut builder = QueryBuilder::<MySql>::new(format!(
+            r#"
+            SELECT
+                c.id AS category_id, c.name AS category_name, c.parent_id, lines.type,
+                CAST(SUM(lines.amount) AS SIGNED) AS total_amount,
+                COUNT(DISTINCT lines.transaction_id) AS transaction_count,
+                {budget} AS budget_amount
+            FROM (
+                SELECT
+                    filtered.id AS transaction_id, filtered.group_id, filtered.owner_user_id, filtered.type,
+                    CASE WHEN s.id IS NULL THEN filtered.category_id ELSE s.category_id END AS category_id,
+                    CASE WHEN s.id IS NULL THEN filtered.amount ELSE s.amount END AS amount
+                FROM (
+                    SELECT t.id, t.group_id, t.owner_user_id, t.type, t.category_id, t.amount
+                    FROM transactions t
+                    WHERE "#,
+        ));
+        push_scope(&mut builder, scope);
+        push_filter(&mut builder, filter);
+        builder.push(format!(
+            r#"
+                ) AS filtered
+                LEFT JOIN transaction_splits s ON s.transaction_id = filtered.id
+            ) AS lines
+            -- 거래와 같은 범위의 카테고리만 집계 (다른 범위의 카테고리는 카테고리 없음으로 취급)
+            LEFT JOIN categories leaf ON leaf.id = lines.category_id
+                AND (leaf.group_id = lines.group_id
+                     OR (lines.group_id IS NULL AND leaf.group_id IS NULL AND leaf.created_by = lines.owner_user_id))
+            LEFT JOIN categories c ON c.id = {category}
+            GROUP BY c.id, c.name, c.parent_id, lines.type, c.budget_amount
+            ORDER BY lines.type, total_amount DESC"#,
+        ));
+
+        let rows = builder.build().fetch_all(&self.pool).await?;
+        let totals = rows
+            .iter()
+            .map(|row| {
+                Ok(CategoryTotal {
+                    category_id: row.try_get("category_id")?,
+                    category_name: row.try_get("category_name")?,
+                    parent_id: row.try_get("parent_id")?,
+                    transaction_type: row.try_get_unchecked("type")?,
+                    total_amount: row.try_get("total_amount")?,
+                    transaction_count: row.try_get("transaction_count")?,
+                    budget_amount: row.try_get("budget_amount")?,
+                })
+            })
+            .collect::<Result<Vec<_>, sqlx::Error>>()?;
+
+        Ok(totals)
     }
 
-    async fn find_by_owner(
+    async fn search(
         &self,
-        owner_user_id: i64,
-        start_date: Option<NaiveDate>,
-        end_date: Option<NaiveDate>,
+        scope: TransactionScope,
+        terms: &[String],
         limit: u32,
-        offset: u32,
-    ) -> Result<Vec<Transaction>, AppError> {
-        let transactions = sqlx::query_as!(
-            Transaction,
+    ) -> Result<Vec<TransactionSearchHit>, AppError> {
+        let Some(against) = boolean_fulltext_query(terms) else {
+            return Ok(Vec::new());
+        };
+
+        // 두 FULLTEXT 인덱스를 각각 사용하도록 나눠 찾은 뒤 거래별로 점수를 합산
+        let mut builder = QueryBuilder::<MySql>::new(
             r#"
-            SELECT 
-                id, group_id, owner_user_id, type as `type: _`, date, amount, currency_code,
-                original_amount, category_id, tag_id, recurring_rule_id, receipt_id,
-                merchant, memo, created_at, updated_at
-            FROM transactions
-            WHERE owner_user_id = ?
-              AND (? IS NULL OR date >= ?)
-              AND (? IS NULL OR date <= ?)
-            ORDER BY date DESC, created_at DESC
-            LIMIT ? OFFSET ?
-            "#,
-            owner_user_id,
-            start_date, start_date,
-            end_date, end_date,
-            limit, offset
-        )
-        .fetch_all(&self.pool)
-        .await?;
+            SELECT
+                t.id, t.group_id, t.owner_user_id, t.type, t.date, t.amount, t.currency_code,
+                t.original_amount, t.category_id, t.recurring_rule_id, t.receipt_id,
+                t.merchant, t.memo, t.created_at, t.updated_at,
+                hits.score,
+                (SELECT r.ocr_text FROM receipts r WHERE r.transaction_id = t.id ORDER BY r.id LIMIT 1) AS receipt_text
+            FROM (
+                SELECT transaction_id, SUM(score) AS score
+                FROM (
+                    SELECT t.id AS transaction_id,
+                           MATCH(t.merchant, t.memo) AGAINST ("#,
+        );
+        builder
+            .push_bind(against.clone())
+            .push(" IN BOOLEAN MODE) AS score FROM transactions t WHERE ");
+        push_scope(&mut builder, scope);
+        builder
+            .push(" AND MATCH(t.merchant, t.memo) AGAINST (")
+            .push_bind(against.clone())
+            .push(
+                r#" IN BOOLEAN MODE)
+                    UNION ALL
+                    SELECT r.transaction_id,
+                           MATCH(r.ocr_text) AGAINST ("#,
+            )
+            .push_bind(against.clone())
+            .push(" IN BOOLEAN MODE) FROM receipts r INNER JOIN transactions t ON t.id = r.transaction_id WHERE ");
+        push_scope(&mut builder, scope);
+        builder
+            .push(" AND MATCH(r.ocr_text) AGAINST (")
+            .push_bind(against)
+            .push(
+                r#" IN BOOLEAN MODE)
+                ) AS matched
+                GROUP BY transaction_id
+            ) AS hits
+            INNER JOIN transactions t ON t.id = hits.transaction_id
+            ORDER BY hits.score DESC, t.date DESC, t.id DESC
+            LIMIT "#,
+            )
+            .push_bind(limit);
+
+        let rows = builder.build().fetch_all(&self.pool).await?;
+        let hits = rows
+            .iter()
+            .map(|row| {
+                Ok(TransactionSearchHit {
+                    transaction: transaction_from_row(row)?,
+                    score: row.try_get("score")?,
+                    receipt_text: row.try_get("receipt_text")?,
+                })
+            })
+            .collect::<Result<Vec<_>, sqlx::Error>>()?;
+
+        Ok(hits)
+    }
+}
+
+impl TransactionRepositoryImpl {
+    /// 범위 조건(`WHERE ...`)까지 만든 쿼리에 필터, 커서, 정렬을 붙여 실행합니다.
+    async fn fetch_page(
+        &self,
+        mut builder: QueryBuilder<'_, MySql>,
+        filter: &TransactionFilter,
+        sort: TransactionSort,
+        cursor: Option<&TransactionCursor>,
+        limit: u32,
+    ) -> Result<Vec<Transaction>, AppError> {
+        push_filter(&mut builder, filter);
+        if let Some(cursor) = cursor {
+            push_cursor(&mut builder, cursor);
+        }
+        builder.push(order_by(sort));
+        builder.push(" LIMIT ").push_bind(limit);
+
+        let rows = builder.build().fetch_all(&self.pool).await?;
+        let transactions = rows
+            .iter()
+            .map(transaction_from_row)
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(transactions)
     }
+}
 
-    async fn update(&self, transaction: &Transaction) -> Result<Transaction, AppError> {
-        sqlx::query!(
-            r#"
-            UPDATE transactions
-            SET group_id = ?, owner_user_id = ?, type = ?, date = ?, amount = ?,
-                currency_code = ?, original_amount = ?, category_id = ?, tag_id = ?,
-                recurring_rule_id = ?, receipt_id = ?, merchant = ?, memo = ?
-            WHERE id = ?
-            "#,
-            transaction.group_id,
-            transaction.owner_user_id,
-            transaction.transaction_type,
-            transaction.date,
-            transaction.amount,
-            transaction.currency_code,
-            transaction.original_amount,
-            transaction.category_id,
-            transaction.tag_id,
-            transaction.recurring_rule_id,
-            transaction.receipt_id,
-            transaction.merchant,
-            transaction.memo,
-            transaction.id
-        )
-        .execute(&self.pool)
-        .await?;
+/// MySQL `ngram_token_size` 기본값
+const NGRAM_TOKEN_SIZE: usize = 2;
 
-        self.find_by_id(transaction.id).await?
-            .ok_or_else(|| AppError::NotFound(format!("Transaction with id {} not found", transaction.id)))
+const SELECT_TRANSACTIONS: &str = r#"
+    SELECT
+        id, group_id, owner_user_id, type, date, amount, currency_code,
+        original_amount, category_id, recurring_rule_id, receipt_id,
+        merchant, memo, created_at, updated_at
+    FROM transactions
+"#;
+
+/// 조회 조건을 `AND ...`로 덧붙입니다. 모든 값은 바인딩 파라미터로 전달합니다.
+fn push_filter(builder: &mut QueryBuilder<'_, MySql>, filter: &TransactionFilter) {
+    if let Some(start_date) = filter.start_date {
+        builder.push(" AND date >= ").push_bind(start_date);
+    }
+    if let Some(end_date) = filter.end_date {
+        builder.push(" AND date <= ").push_bind(end_date);
+    }
+    if let Some(transaction_type) = filter.transaction_type {
+        builder.push(" AND type = ").push_bind(transaction_type);
+    }
+    if let Some(min_amount) = filter.min_amount {
+        builder.push(" AND amount >= ").push_bind(min_amount);
+    }
+    if let Some(max_amount) = filter.max_amount {
+        builder.push(" AND amount <= ").push_bind(max_amount);
+    }
+    if !filter.category_ids.is_empty() {
+        // 하위 카테고리나 분할 항목의 카테고리가 일치하는 거래도 포함
+        builder.push(" AND (category_id IN (");
+        push_category_tree(builder, &filter.category_ids);
+        builder.push(") OR id IN (SELECT transaction_id FROM transaction_splits WHERE category_id IN (");
+        push_category_tree(builder, &filter.category_ids);
+        builder.push(")))");
     }
+    if !filter.tag_ids.is_empty() {
+        // 태그 중 하나라도 붙은 거래
+        builder.push(" AND id IN (SELECT transaction_id FROM transaction_tags WHERE tag_id IN (");
+        let mut ids = builder.separated(", ");
+        for tag_id in &filter.tag_ids {
+            ids.push_bind(*tag_id);
+        }
+        builder.push("))");
+    }
+    if let Some(merchant) = &filter.merchant {
+        builder.push(" AND merchant = ").push_bind(merchant.clone());
+    }
+    if let Some(owner_user_id) = filter.owner_user_id {
+        builder.push(" AND owner_user_id = ").push_bind(owner_user_id);
+    }
+    if let Some(currency_code) = &filter.currency_code {
+        builder.push(" AND currency_code = ").push_bind(currency_code.clone());
+    }
+    match filter.has_receipt {
+        Some(true) => { builder.push(" AND receipt_id IS NOT NULL"); }
+        Some(false) => { builder.push(" AND receipt_id IS NULL"); }
+        None => {}
+    }
+    match filter.from_recurring {
+        Some(true) => { builder.push(" AND recurring_rule_id IS NOT NULL"); }
+        Some(false) => { builder.push(" AND recurring_rule_id IS NULL"); }
+        None => {}
+    }
+    if let Some(search) = &filter.search {
+        match boolean_fulltext_query(&search_terms(search)) {
+            Some(against) => {
+                builder
+                    .push(" AND MATCH(merchant, memo) AGAINST (")
+                    .push_bind(against)
+                    .push(" IN BOOLEAN MODE)");
+            }
+            // ngram 단위보다 짧은 검색어는 인덱스로 찾을 수 없어 부분 일치로 대체
+            None => {
+                let pattern = format!("%{}%", escape_like(search));
+                builder
+                    .push(" AND (merchant LIKE ")
+                    .push_bind(pattern.clone())
+                    .push(r" ESCAPE '\\' OR memo LIKE ")
+                    .push_bind(pattern)
+                    .push(r" ESCAPE '\\')");
+            }
+        }
+    }
+}
 
-    async fn delete(&self, id: i64) -> Result<(), AppError> {
-        sqlx::query!("DELETE FROM transactions WHERE id = ?", id)
-            .execute(&self.pool)
-            .await?;
-        Ok(())
+/// 검색어의 `%`, `_`, `\`를 LIKE 패턴에서 문자 그대로 찾도록 이스케이프합니다.
+fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
     }
+    escaped
+}
 
-    async fn count_by_group(
-        &self,
-        group_id: Option<i64>,
-        start_date: Option<NaiveDate>,
-        end_date: Option<NaiveDate>,
-    ) -> Result<i64, AppError> {
-        let count: i64 = sqlx::query_scalar!(
-            r#"
-            SELECT COUNT(*) as count
-            FROM transactions
-            WHERE (? IS NULL OR group_id = ?)
-              AND (? IS NULL OR date >= ?)
-              AND (? IS NULL OR date <= ?)
-            "#,
-            group_id, group_id,
-            start_date, start_date,
-            end_date, end_date
-        )
-        .fetch_one(&self.pool)
-        .await?;
+/// `category_ids`와 그 하위 카테고리의 ID를 조회하는 서브쿼리를 덧붙입니다.
+fn push_category_tree(builder: &mut QueryBuilder<'_, MySql>, category_ids: &[i64]) {
+    builder.push("SELECT id FROM categories WHERE id IN (");
+    let mut ids = builder.separated(", ");
+    for category_id in category_ids {
+        ids.push_bind(*category_id);
+    }
+    builder.push(") OR parent_id IN (");
+    let mut ids = builder.separated(", ");
+    for category_id in category_ids {
+        ids.push_bind(*category_id);
+    }
+    builder.push(")");
+}
 
-        Ok(count)
+fn push_scope(builder: &mut QueryBuilder<'_, MySql>, scope: TransactionScope) {
+    match scope {
+        TransactionScope::Group(group_id) => builder.push("t.group_id = ").push_bind(group_id),
+        TransactionScope::Owner(owner_user_id) => builder.push("t.owner_user_id = ").push_bind(owner_user_id),
+    };
+}
+
+/// 모든 검색어를 포함해야 하는 BOOLEAN MODE 검색식 (`+"단어1" +"단어2"`)
+///
+/// ngram 토큰(기본 2글자)보다 짧은 단어는 인덱스로 찾을 수 없어 제외합니다.
+/// 남는 단어가 없으면 `None`입니다.
+fn boolean_fulltext_query(terms: &[String]) -> Option<String> {
+    let words: Vec<String> = terms
+        .iter()
+        .filter(|term| term.chars().count() >= NGRAM_TOKEN_SIZE)
+        .map(|term| format!("+\"{}\"", term))
+        .collect();
+
+    if words.is_empty() {
+        None
+    } else {
+        Some(words.join(" "))
+    }
+}
+
+/// 커서보다 뒤에 오는 거래만 남기는 조건 (정렬 키를 순서대로 비교)
+fn push_cursor(builder: &mut QueryBuilder<'_, MySql>, cursor: &TransactionCursor) {
+    let after = match cursor.sort.direction {
+        SortDirection::Desc => " < ",
+        SortDirection::Asc => " > ",
+    };
+
+    match cursor.sort.field {
+        TransactionSortField::Date => {
+            builder
+                .push(" AND (date").push(after).push_bind(cursor.date)
+                .push(" OR (date = ").push_bind(cursor.date)
+                .push(" AND (created_at").push(after).push_bind(cursor.created_at)
+                .push(" OR (created_at = ").push_bind(cursor.created_at)
+                .push(" AND id").push(after).push_bind(cursor.id)
+                .push("))))");
+        }
+        TransactionSortField::Amount => {
+            builder
+                .push(" AND (amount").push(after).push_bind(cursor.amount)
+                .push(" OR (amount = ").push_bind(cursor.amount)
+                .push(" AND id").push(after).push_bind(cursor.id)
+                .push("))");
+        }
+    }
+}
+
+fn order_by(sort: TransactionSort) -> &'static str {
+    match (sort.field, sort.direction) {
+        (TransactionSortField::Date, SortDirection::Desc) => " ORDER BY date DESC, created_at DESC, id DESC",
+        (TransactionSortField::Date, SortDirection::Asc) => " ORDER BY date ASC, created_at ASC, id ASC",
+        (TransactionSortField::Amount, SortDirection::Desc) => " ORDER BY amount DESC, id DESC",
+        (TransactionSortField::Amount, SortDirection::Asc) => " ORDER BY amount ASC, id ASC",
     }
 }
 
+/// 동적 쿼리 결과를 `Transaction`으로 변환합니다.
+///
+/// MySQL은 ENUM 컬럼을 문자열 타입으로 보내므로 `type`은 타입 검사 없이 디코딩합니다.
+fn transaction_from_row(row: &MySqlRow) -> Result<Transaction, sqlx::Error> {
+    Ok(Transaction {
+        id: row.try_get("id")?,
+        group_id: row.try_get("group_id")?,
+        owner_user_id: row.try_get("owner_user_id")?,
+        transaction_type: row.try_get_unchecked("type")?,
+        date: row.try_get("date")?,
+        amount: row.try_get("amount")?,
+        currency_code: row.try_get("currency_code")?,
+        original_amount: row.try_get("original_amount")?,
+        category_id: row.try_get("category_id")?,
+        recurring_rule_id: row.try_get("recurring_rule_id")?,
+        receipt_id: row.try_get("receipt_id")?,
+        merchant: row.try_get("merchant")?,
+        memo: row.try_get("memo")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
@@ -3,6 +3,8 @@ use sqlx::MySqlPool;
 use crate::domain::models::User;
 use crate::domain::repositories::UserRepository;
 use crate::errors::AppError;
+use crate::i18n::MessageKey;
+use super::group_invite_repository_impl::consume_invite;
 
 pub struct UserRepositoryImpl {
     pool: MySqlPool,
@@ -20,11 +22,12 @@ impl UserRepository for UserRepositoryImpl {
         let result = sqlx::query_as!(
             User,
             r#"
-            INSERT INTO users (email, password_hash, nickname, avatar_url, group_id, default_currency, settings)
-            VALUES (?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO users (email, password_hash, email_verified_at, nickname, avatar_url, group_id, default_currency, settings)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
             "#,
             user.email,
             user.password_hash,
+            user.email_verified_at,
             user.nickname,
             user.avatar_url,
             user.group_id,
@@ -36,14 +39,52 @@ impl UserRepository for UserRepositoryImpl {
 
         let id = result.last_insert_id();
         self.find_by_id(id as i64).await?
-            .ok_or_else(|| AppError::NotFound(format!("User with id {} not found", id)))
+            .ok_or_else(|| AppError::NotFound(MessageKey::UserNotFound))
+    }
+
+    async fn create_with_invite(&self, user: &User, invite_code: &str) -> Result<User, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        // 초대 코드 검증과 사용 횟수 증가를 가입과 같은 트랜잭션에서 처리
+        let invite = consume_invite(&mut tx, invite_code).await?;
+
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO users (email, password_hash, email_verified_at, nickname, avatar_url, group_id, default_currency, settings)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+            user.email,
+            user.password_hash,
+            user.email_verified_at,
+            user.nickname,
+            user.avatar_url,
+            invite.group_id,
+            user.default_currency,
+            user.settings
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let id = result.last_insert_id();
+        sqlx::query!(
+            "INSERT INTO group_members (group_id, user_id) VALUES (?, ?)",
+            invite.group_id,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        self.find_by_id(id as i64).await?
+            .ok_or_else(|| AppError::NotFound(MessageKey::UserNotFound))
     }
 
     async fn find_by_id(&self, id: i64) -> Result<Option<User>, AppError> {
         let user = sqlx::query_as!(
             User,
             r#"
-            SELECT id, email, password_hash, nickname, avatar_url, group_id, default_currency, settings, created_at
+            SELECT id, email, password_hash, email_verified_at, nickname, avatar_url, group_id, default_currency, settings, created_at
             FROM users
             WHERE id = ?
             "#,
@@ -59,7 +100,7 @@ impl UserRepository for UserRepositoryImpl {
         let user = sqlx::query_as!(
             User,
             r#"
-            SELECT id, email, password_hash, nickname, avatar_url, group_id, default_currency, settings, created_at
+            SELECT id, email, password_hash, email_verified_at, nickname, avatar_url, group_id, default_currency, settings, created_at
             FROM users
             WHERE email = ?
             "#,
@@ -91,7 +132,21 @@ impl UserRepository for UserRepositoryImpl {
         .await?;
 
         self.find_by_id(user.id).await?
-            .ok_or_else(|| AppError::NotFound(format!("User with id {} not found", user.id)))
+            .ok_or_else(|| AppError::NotFound(MessageKey::UserNotFound))
+    }
+
+    async fn mark_email_verified(&self, id: i64) -> Result<(), AppError> {
+        sqlx::query!(
+            r#"
+            UPDATE users
+            SET email_verified_at = CURRENT_TIMESTAMP
+            WHERE id = ? AND email_verified_at IS NULL
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
     }
 
     async fn delete(&self, id: i64) -> Result<(), AppError> {
@@ -105,9 +160,11 @@ impl UserRepository for UserRepositoryImpl {
         let users = sqlx::query_as!(
             User,
             r#"
-            SELECT id, email, password_hash, nickname, avatar_url, group_id, default_currency, settings, created_at
-            FROM users
-            WHERE group_id = ?
+            SELECT u.id, u.email, u.password_hash, u.email_verified_at, u.nickname, u.avatar_url, u.group_id,
+                   u.default_currency, u.settings, u.created_at
+            FROM users u
+            INNER JOIN group_members gm ON gm.user_id = u.id
+            WHERE gm.group_id = ?
             "#,
             group_id
         )
@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use crate::domain::models::{TokenPurpose, UserToken};
+use crate::domain::repositories::UserTokenRepository;
+use crate::errors::AppError;
+
+pub struct UserTokenRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl UserTokenRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserTokenRepository for UserTokenRepositoryImpl {
+    async fn create(&self, token: &UserToken) -> Result<UserToken, AppError> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO user_tokens (user_id, purpose, token_hash, expires_at)
+            VALUES (?, ?, ?, ?)
+            "#,
+            token.user_id,
+            token.purpose,
+            token.token_hash,
+            token.expires_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        let id = result.last_insert_id() as i64;
+        let created = sqlx::query_as!(
+            UserToken,
+            r#"
+            SELECT id, user_id, purpose as `purpose: _`, token_hash, expires_at, used_at, created_at
+            FROM user_tokens
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| AppError::Internal(format!("User token with id {} not found", id)))?;
+
+        Ok(created)
+    }
+
+    async fn find_valid_by_hash(
+        &self,
+        purpose: TokenPurpose,
+        token_hash: &str,
+    ) -> Result<Option<UserToken>, AppError> {
+        let token = sqlx::query_as!(
+            UserToken,
+            r#"
+            SELECT id, user_id, purpose as `purpose: _`, token_hash, expires_at, used_at, created_at
+            FROM user_tokens
+            WHERE token_hash = ?
+              AND purpose = ?
+              AND used_at IS NULL
+              AND expires_at > CURRENT_TIMESTAMP
+            "#,
+            token_hash,
+            purpose
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(token)
+    }
+
+    async fn mark_used(&self, id: i64) -> Result<bool, AppError> {
+        // 같은 토큰으로 동시에 들어온 요청 중 하나만 성공하도록 조건부 UPDATE
+        let result = sqlx::query!(
+            r#"
+            UPDATE user_tokens
+            SET used_at = CURRENT_TIMESTAMP
+            WHERE id = ? AND used_at IS NULL
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn invalidate_for_user(&self, user_id: i64, purpose: TokenPurpose) -> Result<(), AppError> {
+        sqlx::query!(
+            r#"
+            UPDATE user_tokens
+            SET used_at = CURRENT_TIMESTAMP
+            WHERE user_id = ? AND purpose = ? AND used_at IS NULL
+            "#,
+            user_id,
+            purpose
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
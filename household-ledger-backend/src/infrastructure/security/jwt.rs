@@ -2,56 +2,104 @@ use jsonwebtoken::{encode, decode, Header, Algorithm, Validation, EncodingKey, D
 use serde::{Deserialize, Serialize};
 use crate::errors::AppError;
 
+pub const ACCESS_TOKEN_TTL_MINUTES: i64 = 15;
+pub const REFRESH_TOKEN_TTL_DAYS: i64 = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenType {
+    Access,
+    Refresh,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
     pub user_id: i64,
     pub email: String,
+    /// 토큰 종류 (access/refresh)
+    pub typ: TokenType,
+    /// 토큰 고유 ID
+    pub jti: String,
+    /// 세션 ID (refresh token family)
+    pub sid: String,
+    pub iat: usize,
     pub exp: usize,
 }
 
 pub struct JwtService {
     encoding_key: EncodingKey,
     decoding_key: DecodingKey,
+    refresh_encoding_key: EncodingKey,
+    refresh_decoding_key: DecodingKey,
 }
 
 impl JwtService {
-    pub fn new(secret: &str) -> Self {
+    pub fn new(secret: &str, refresh_secret: &str) -> Self {
         Self {
             encoding_key: EncodingKey::from_secret(secret.as_ref()),
             decoding_key: DecodingKey::from_secret(secret.as_ref()),
+            refresh_encoding_key: EncodingKey::from_secret(refresh_secret.as_ref()),
+            refresh_decoding_key: DecodingKey::from_secret(refresh_secret.as_ref()),
         }
     }
 
-    pub fn generate_access_token(&self, user_id: i64, email: &str) -> Result<String, AppError> {
-        let exp = (chrono::Utc::now() + chrono::Duration::minutes(15)).timestamp() as usize;
+    pub fn generate_access_token(&self, user_id: i64, email: &str, sid: &str) -> Result<String, AppError> {
+        let now = chrono::Utc::now();
         let claims = Claims {
             user_id,
             email: email.to_string(),
-            exp,
+            typ: TokenType::Access,
+            jti: uuid::Uuid::new_v4().to_string(),
+            sid: sid.to_string(),
+            iat: now.timestamp() as usize,
+            exp: (now + chrono::Duration::minutes(ACCESS_TOKEN_TTL_MINUTES)).timestamp() as usize,
         };
         encode(&Header::new(Algorithm::HS256), &claims, &self.encoding_key)
             .map_err(|e| AppError::Jwt(format!("Failed to encode token: {}", e)))
     }
 
-    pub fn generate_refresh_token(&self, user_id: i64, email: &str) -> Result<String, AppError> {
-        let exp = (chrono::Utc::now() + chrono::Duration::days(7)).timestamp() as usize;
+    pub fn generate_refresh_token(
+        &self,
+        user_id: i64,
+        email: &str,
+        sid: &str,
+        jti: &str,
+    ) -> Result<String, AppError> {
+        let now = chrono::Utc::now();
         let claims = Claims {
             user_id,
             email: email.to_string(),
-            exp,
+            typ: TokenType::Refresh,
+            jti: jti.to_string(),
+            sid: sid.to_string(),
+            iat: now.timestamp() as usize,
+            exp: (now + chrono::Duration::days(REFRESH_TOKEN_TTL_DAYS)).timestamp() as usize,
         };
-        encode(&Header::new(Algorithm::HS256), &claims, &self.encoding_key)
+        encode(&Header::new(Algorithm::HS256), &claims, &self.refresh_encoding_key)
             .map_err(|e| AppError::Jwt(format!("Failed to encode refresh token: {}", e)))
     }
 
-    pub fn verify_token(&self, token: &str) -> Result<Claims, AppError> {
+    pub fn verify_access_token(&self, token: &str) -> Result<Claims, AppError> {
+        Self::verify(token, &self.decoding_key, TokenType::Access)
+    }
+
+    pub fn verify_refresh_token(&self, token: &str) -> Result<Claims, AppError> {
+        Self::verify(token, &self.refresh_decoding_key, TokenType::Refresh)
+    }
+
+    fn verify(token: &str, key: &DecodingKey, expected: TokenType) -> Result<Claims, AppError> {
         let token_data = decode::<Claims>(
             token,
-            &self.decoding_key,
+            key,
             &Validation::new(Algorithm::HS256),
         )
         .map_err(|e| AppError::Jwt(format!("Failed to decode token: {}", e)))?;
+
+        // 같은 secret을 쓰더라도 access/refresh 토큰을 서로 대신 쓸 수 없도록 확인
+        if token_data.claims.typ != expected {
+            return Err(AppError::Jwt("Unexpected token type".to_string()));
+        }
+
         Ok(token_data.claims)
     }
 }
-
@@ -1,5 +1,7 @@
 pub mod jwt;
 pub mod password;
+pub mod token;
 
-pub use jwt::{JwtService, Claims};
-pub use password::{hash_password, verify_password};
+pub use jwt::{JwtService, Claims, TokenType};
+pub use password::PasswordService;
+pub use token::{generate_token, hash_token};
@@ -1,13 +1,81 @@
-use bcrypt::{hash, verify, DEFAULT_COST};
+use argon2::password_hash::{rand_core::OsRng, PasswordHash, SaltString};
+use argon2::{Algorithm, Argon2, Params, PasswordHasher, PasswordVerifier, Version};
 use crate::errors::AppError;
 
-pub fn hash_password(password: &str) -> Result<String, AppError> {
-    hash(password, DEFAULT_COST)
-        .map_err(|e| AppError::Password(format!("Failed to hash password: {}", e)))
+/// Argon2id 기본 메모리 비용 (KiB, OWASP 권장값 19 MiB)
+pub const DEFAULT_MEMORY_COST_KIB: u32 = 19 * 1024;
+/// Argon2id 기본 반복 횟수
+pub const DEFAULT_TIME_COST: u32 = 2;
+const PARALLELISM: u32 = 1;
+
+/// 비밀번호 해싱 서비스
+///
+/// 새 해시는 Argon2id PHC 문자열로 만들고, 검증은 기존 BCrypt 해시도 지원합니다.
+pub struct PasswordService {
+    params: Params,
 }
 
-pub fn verify_password(password: &str, hash: &str) -> Result<bool, AppError> {
-    verify(password, hash)
-        .map_err(|e| AppError::Password(format!("Failed to verify password: {}", e)))
+impl PasswordService {
+    pub fn new(memory_cost_kib: u32, time_cost: u32) -> Result<Self, AppError> {
+        let params = Params::new(memory_cost_kib, time_cost, PARALLELISM, None)
+            .map_err(|e| AppError::Configuration(format!("Invalid Argon2 parameters: {}", e)))?;
+
+        Ok(Self { params })
+    }
+
+    pub fn hash_password(&self, password: &str) -> Result<String, AppError> {
+        let salt = SaltString::generate(&mut OsRng);
+        self.argon2()
+            .hash_password(password.as_bytes(), &salt)
+            .map(|hash| hash.to_string())
+            .map_err(|e| AppError::Password(format!("Failed to hash password: {}", e)))
+    }
+
+    pub fn verify_password(&self, password: &str, hash: &str) -> Result<bool, AppError> {
+        if is_bcrypt_hash(hash) {
+            return bcrypt::verify(password, hash)
+                .map_err(|e| AppError::Password(format!("Failed to verify password: {}", e)));
+        }
+
+        let parsed = PasswordHash::new(hash)
+            .map_err(|e| AppError::Password(format!("Failed to parse password hash: {}", e)))?;
+
+        match self.argon2().verify_password(password.as_bytes(), &parsed) {
+            Ok(()) => Ok(true),
+            Err(argon2::password_hash::Error::Password) => Ok(false),
+            Err(e) => Err(AppError::Password(format!("Failed to verify password: {}", e))),
+        }
+    }
+
+    /// BCrypt 해시이거나 현재 설정과 다른 Argon2 파라미터로 만든 해시이면 `true`
+    pub fn needs_rehash(&self, hash: &str) -> bool {
+        if is_bcrypt_hash(hash) {
+            return true;
+        }
+
+        let Ok(parsed) = PasswordHash::new(hash) else {
+            return true;
+        };
+
+        if parsed.algorithm != argon2::ARGON2ID_IDENT {
+            return true;
+        }
+
+        match Params::try_from(&parsed) {
+            Ok(params) => {
+                params.m_cost() != self.params.m_cost()
+                    || params.t_cost() != self.params.t_cost()
+                    || params.p_cost() != self.params.p_cost()
+            }
+            Err(_) => true,
+        }
+    }
+
+    fn argon2(&self) -> Argon2<'_> {
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params.clone())
+    }
 }
 
+fn is_bcrypt_hash(hash: &str) -> bool {
+    hash.starts_with("$2a$") || hash.starts_with("$2b$") || hash.starts_with("$2y$")
+}
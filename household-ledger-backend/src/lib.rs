@@ -2,19 +2,26 @@ pub mod api;
 pub mod application;
 pub mod domain;
 pub mod errors;
+pub mod i18n;
 pub mod infrastructure;
 pub mod schemas;
 pub mod utils;
 
 use axum::Router;
+use api::middleware::TrustedProxies;
 use errors::AppError;
 use infrastructure::database::{pool::create_pool, migrations::run_migrations};
-use infrastructure::security::JwtService;
+use infrastructure::mail::{Mailer, OutboxMailer, SmtpMailer};
+use infrastructure::security::{JwtService, PasswordService};
+use infrastructure::security::password::{DEFAULT_MEMORY_COST_KIB, DEFAULT_TIME_COST};
 use infrastructure::repositories::{
-    UserRepositoryImpl, GroupRepositoryImpl, TransactionRepositoryImpl, CategoryRepositoryImpl,
+    UserRepositoryImpl, GroupRepositoryImpl, GroupInviteRepositoryImpl, GroupMemberRepositoryImpl,
+    TransactionRepositoryImpl, CategoryRepositoryImpl,
+    SessionRepositoryImpl, TagRepositoryImpl, UserTokenRepositoryImpl,
 };
+use application::policies::EmailVerificationPolicy;
 use application::services::{
-    AuthService, TransactionService, CategoryService, GroupService,
+    AuthService, TransactionService, CategoryService, GroupService, TagService,
 };
 use std::env;
 use tower_http::cors::CorsLayer;
@@ -28,6 +35,8 @@ pub struct AppState {
     pub transaction_service: Arc<TransactionService>,
     pub category_service: Arc<CategoryService>,
     pub group_service: Arc<GroupService>,
+    pub tag_service: Arc<TagService>,
+    pub trusted_proxies: Arc<TrustedProxies>,
 }
 
 pub async fn create_app() -> Result<Router, AppError> {
@@ -43,19 +52,74 @@ pub async fn create_app() -> Result<Router, AppError> {
     // JWT 서비스 초기화
     let jwt_secret = env::var("JWT_SECRET")
         .map_err(|_| AppError::Configuration("JWT_SECRET 환경 변수가 설정되지 않았습니다".to_string()))?;
-    let jwt_service = Arc::new(JwtService::new(&jwt_secret));
+    // refresh token 전용 secret이 없으면 access token secret을 사용 (토큰 종류는 typ으로 구분)
+    let jwt_refresh_secret = env::var("JWT_REFRESH_SECRET").unwrap_or_else(|_| jwt_secret.clone());
+    let jwt_service = Arc::new(JwtService::new(&jwt_secret, &jwt_refresh_secret));
+
+    // 비밀번호 해싱 서비스 초기화 (Argon2id 비용은 환경 변수로 조정 가능)
+    let password_service = Arc::new(PasswordService::new(
+        env_u32("PASSWORD_HASH_MEMORY_KIB", DEFAULT_MEMORY_COST_KIB)?,
+        env_u32("PASSWORD_HASH_TIME_COST", DEFAULT_TIME_COST)?,
+    )?);
+
+    // 메일 발송 (SMTP_HOST가 없으면 로컬 outbox 디렉터리에 저장)
+    let mailer = create_mailer()?;
+    let app_base_url = env::var("APP_BASE_URL").unwrap_or_else(|_| "http://localhost:3000".to_string());
+
+    // 이메일 미인증 계정 제한 (예: "join_group,create_invite", 제한 없음은 "none")
+    let verification_policy = Arc::new(match env::var("UNVERIFIED_RESTRICTED_ACTIONS") {
+        Ok(value) => EmailVerificationPolicy::parse(&value)?,
+        Err(_) => EmailVerificationPolicy::default(),
+    });
+
+    // 클라이언트 IP 전달 헤더를 믿을 프록시 (예: "10.0.0.0/8,fdaa::/16", 없으면 연결 주소만 사용)
+    let trusted_proxies = Arc::new(match env::var("TRUSTED_PROXIES") {
+        Ok(value) => TrustedProxies::parse(&value)?,
+        Err(_) => TrustedProxies::default(),
+    });
 
     // Repository 구현체 생성
-    let user_repo = Box::new(UserRepositoryImpl::new(pool.clone()));
     let group_repo = Box::new(GroupRepositoryImpl::new(pool.clone()));
     let transaction_repo = Box::new(TransactionRepositoryImpl::new(pool.clone()));
     let category_repo = Box::new(CategoryRepositoryImpl::new(pool.clone()));
 
-    // 서비스 생성
-    let auth_service = Arc::new(AuthService::new(user_repo.clone(), jwt_service.clone()));
-    let transaction_service = Arc::new(TransactionService::new(transaction_repo));
-    let category_service = Arc::new(CategoryService::new(category_repo));
-    let group_service = Arc::new(GroupService::new(group_repo, user_repo));
+    // 서비스 생성 (UserRepository는 서비스마다 별도 인스턴스 사용)
+    let auth_service = Arc::new(AuthService::new(
+        Box::new(UserRepositoryImpl::new(pool.clone())),
+        Box::new(SessionRepositoryImpl::new(pool.clone())),
+        Box::new(UserTokenRepositoryImpl::new(pool.clone())),
+        jwt_service.clone(),
+        password_service,
+        mailer,
+        app_base_url,
+    ));
+    let transaction_service = Arc::new(TransactionService::new(
+        transaction_repo,
+        Box::new(CategoryRepositoryImpl::new(pool.clone())),
+        Box::new(TagRepositoryImpl::new(pool.clone())),
+        Box::new(GroupMemberRepositoryImpl::new(pool.clone())),
+    ));
+    let category_service = Arc::new(CategoryService::new(
+        category_repo,
+        Box::new(GroupMemberRepositoryImpl::new(pool.clone())),
+    ));
+    let tag_service = Arc::new(TagService::new(
+        Box::new(TagRepositoryImpl::new(pool.clone())),
+        Box::new(GroupMemberRepositoryImpl::new(pool.clone())),
+    ));
+    let group_service = Arc::new(GroupService::new(
+        group_repo,
+        Box::new(UserRepositoryImpl::new(pool.clone())),
+        Box::new(GroupInviteRepositoryImpl::new(pool.clone())),
+        Box::new(GroupMemberRepositoryImpl::new(pool.clone())),
+        verification_policy,
+    ));
+
+    // 만료된 초대 코드 주기적 정리 (기본 1시간)
+    spawn_invite_cleanup(
+        group_service.clone(),
+        env_u32("INVITE_CLEANUP_INTERVAL_SECS", 3600)?,
+    );
 
     // AppState 생성
     let state = AppState {
@@ -65,6 +129,8 @@ pub async fn create_app() -> Result<Router, AppError> {
         transaction_service,
         category_service,
         group_service,
+        tag_service,
+        trusted_proxies,
     };
 
     // CORS 설정
@@ -72,13 +138,61 @@ pub async fn create_app() -> Result<Router, AppError> {
 
     let app = Router::new()
         .route("/health", axum::routing::get(health_check))
-        .merge(api::router())
+        .merge(api::router(state.clone()))
         .layer(cors)
+        .layer(axum::middleware::from_fn(api::middleware::request_context_middleware))
         .with_state(state);
 
     Ok(app)
 }
 
+fn spawn_invite_cleanup(group_service: Arc<GroupService>, interval_secs: u32) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(interval_secs.max(1) as u64));
+        loop {
+            interval.tick().await;
+            match group_service.cleanup_expired_invites().await {
+                Ok(0) => {}
+                Ok(deleted) => tracing::info!("만료된 초대 코드 {}개를 삭제했습니다", deleted),
+                Err(e) => tracing::warn!("초대 코드 정리 실패: {}", e),
+            }
+        }
+    });
+}
+
+fn create_mailer() -> Result<Arc<dyn Mailer>, AppError> {
+    let Ok(host) = env::var("SMTP_HOST") else {
+        let dir = env::var("MAIL_OUTBOX_DIR").unwrap_or_else(|_| "./outbox".to_string());
+        tracing::info!("SMTP_HOST가 없어 메일을 {}에 저장합니다", dir);
+        return Ok(Arc::new(OutboxMailer::new(dir)));
+    };
+
+    let port = env::var("SMTP_PORT")
+        .ok()
+        .map(|value| value.parse::<u16>())
+        .transpose()
+        .map_err(|_| AppError::Configuration("SMTP_PORT 환경 변수는 포트 번호여야 합니다".to_string()))?
+        .unwrap_or(587);
+    let credentials = match (env::var("SMTP_USERNAME"), env::var("SMTP_PASSWORD")) {
+        (Ok(username), Ok(password)) => Some((username, password)),
+        _ => None,
+    };
+    let from = env::var("MAIL_FROM")
+        .map_err(|_| AppError::Configuration("MAIL_FROM 환경 변수가 설정되지 않았습니다".to_string()))?;
+    let starttls = env::var("SMTP_STARTTLS").map(|value| value != "false").unwrap_or(true);
+
+    Ok(Arc::new(SmtpMailer::new(&host, port, credentials, &from, starttls)?))
+}
+
+fn env_u32(key: &str, default: u32) -> Result<u32, AppError> {
+    match env::var(key) {
+        Ok(value) => value
+            .parse()
+            .map_err(|_| AppError::Configuration(format!("{} 환경 변수는 양의 정수여야 합니다", key))),
+        Err(_) => Ok(default),
+    }
+}
+
 async fn health_check() -> axum::Json<serde_json::Value> {
     axum::Json(serde_json::json!({
         "status": "healthy",
@@ -40,6 +40,7 @@ pub struct AuthResponse {
 pub struct UserResponse {
     pub id: i64,
     pub email: String,
+    pub email_verified: bool,
     pub nickname: String,
     pub avatar_url: Option<String>,
     pub group_id: Option<i64>,
@@ -49,6 +50,7 @@ pub struct UserResponse {
 impl From<crate::domain::models::User> for UserResponse {
     fn from(user: crate::domain::models::User) -> Self {
         Self {
+            email_verified: user.is_email_verified(),
             id: user.id,
             email: user.email,
             nickname: user.nickname,
@@ -62,5 +64,67 @@ impl From<crate::domain::models::User> for UserResponse {
 #[derive(Debug, Serialize)]
 pub struct RefreshTokenResponse {
     pub access_token: String,
+    pub refresh_token: String,
+}
+
+
+#[derive(Debug, Serialize)]
+pub struct SessionResponse {
+    pub id: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: chrono::NaiveDateTime,
+    pub last_used_at: chrono::NaiveDateTime,
+    pub expires_at: chrono::NaiveDateTime,
+    /// 요청을 보낸 현재 세션 여부
+    pub current: bool,
 }
 
+impl SessionResponse {
+    pub fn new(session: crate::domain::models::Session, current_session_id: &str) -> Self {
+        Self {
+            current: session.id == current_session_id,
+            id: session.id,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            created_at: session.created_at,
+            last_used_at: session.last_used_at,
+            expires_at: session.expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RevokeSessionsResponse {
+    pub revoked: u64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ChangePasswordRequest {
+    #[validate(length(min = 1))]
+    pub current_password: String,
+
+    #[validate(length(min = 8))]
+    pub new_password: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ForgotPasswordRequest {
+    #[validate(email)]
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ResetPasswordRequest {
+    #[validate(length(min = 1))]
+    pub token: String,
+
+    #[validate(length(min = 8))]
+    pub new_password: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct VerifyEmailRequest {
+    #[validate(length(min = 1))]
+    pub token: String,
+}
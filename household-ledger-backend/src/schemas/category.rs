@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+use crate::domain::models::{
+    Category, CategoryMergeSummary, CategoryNode, DefaultCategoryResult, TransactionType,
+    DEFAULT_CATEGORY_VERSION,
+};
+
+#[derive(Debug, Serialize)]
+pub struct CategoryResponse {
+    pub id: i64,
+    pub group_id: Option<i64>,
+    pub created_by: i64,
+    pub parent_id: Option<i64>,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub transaction_type: TransactionType,
+    pub color: Option<String>,
+    pub is_default: bool,
+    /// 기본 카테고리 세트의 키 (직접 만든 카테고리는 `null`)
+    pub default_key: Option<String>,
+    pub budget_amount: Option<i64>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<Category> for CategoryResponse {
+    fn from(category: Category) -> Self {
+        Self {
+            id: category.id,
+            group_id: category.group_id,
+            created_by: category.created_by,
+            parent_id: category.parent_id,
+            name: category.name,
+            transaction_type: category.transaction_type,
+            color: category.color,
+            is_default: category.is_default,
+            default_key: category.default_key,
+            budget_amount: category.budget_amount,
+            created_at: category.created_at,
+            updated_at: category.updated_at,
+        }
+    }
+}
+
+/// 카테고리 트리의 한 노드 (하위 카테고리는 `children`)
+#[derive(Debug, Serialize)]
+pub struct CategoryNodeResponse {
+    #[serde(flatten)]
+    pub category: CategoryResponse,
+    pub children: Vec<CategoryNodeResponse>,
+}
+
+impl From<CategoryNode> for CategoryNodeResponse {
+    fn from(node: CategoryNode) -> Self {
+        Self {
+            category: CategoryResponse::from(node.category),
+            children: node.children.into_iter().map(CategoryNodeResponse::from).collect(),
+        }
+    }
+}
+
+/// 카테고리 목록 조회 (`group_id`와 `X-Group-Id` 헤더가 모두 없으면 개인 카테고리)
+#[derive(Debug, Deserialize)]
+pub struct CategoryListQuery {
+    pub group_id: Option<i64>,
+    #[serde(rename = "type")]
+    pub transaction_type: Option<TransactionType>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CategoryCreateRequest {
+    /// 생략하면 개인 카테고리
+    pub group_id: Option<i64>,
+    /// 같은 범위, 같은 유형의 최상위 카테고리 (생략하면 최상위)
+    pub parent_id: Option<i64>,
+    #[validate(length(min = 1, max = 120))]
+    pub name: String,
+    #[serde(rename = "type")]
+    pub transaction_type: TransactionType,
+    #[validate(custom(function = "validate_color"))]
+    pub color: Option<String>,
+    #[validate(range(min = 0))]
+    pub budget_amount: Option<i64>,
+}
+
+/// 카테고리 수정 (그룹과 유형은 바꿀 수 없음, `parent_id`, `color`, `budget_amount`는 생략하면 해제)
+#[derive(Debug, Deserialize, Validate)]
+pub struct CategoryUpdateRequest {
+    pub parent_id: Option<i64>,
+    #[validate(length(min = 1, max = 120))]
+    pub name: String,
+    #[validate(custom(function = "validate_color"))]
+    pub color: Option<String>,
+    #[validate(range(min = 0))]
+    pub budget_amount: Option<i64>,
+}
+
+/// 카테고리 삭제 (`reassign_to`가 있으면 연결된 거래 등을 그 카테고리로 옮긴 뒤 삭제)
+#[derive(Debug, Deserialize)]
+pub struct CategoryDeleteQuery {
+    pub reassign_to: Option<i64>,
+}
+
+/// 이 카테고리를 `target_category_id`에 합치고 삭제
+#[derive(Debug, Deserialize)]
+pub struct CategoryMergeRequest {
+    pub target_category_id: i64,
+}
+
+/// 대상 카테고리로 옮긴 항목 수
+#[derive(Debug, Serialize)]
+pub struct CategoryMergeCounts {
+    pub transactions: u64,
+    pub splits: u64,
+    pub recurring_rules: u64,
+    pub auto_category_rules: u64,
+    pub sub_categories: u64,
+}
+
+impl From<CategoryMergeSummary> for CategoryMergeCounts {
+    fn from(summary: CategoryMergeSummary) -> Self {
+        Self {
+            transactions: summary.transactions,
+            splits: summary.splits,
+            recurring_rules: summary.recurring_rules,
+            auto_category_rules: summary.auto_category_rules,
+            sub_categories: summary.sub_categories,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CategoryMergeResponse {
+    /// 병합 후 대상 카테고리
+    pub category: CategoryResponse,
+    pub moved: CategoryMergeCounts,
+}
+
+/// 기본 카테고리 받기/초기화 (`group_id`와 `X-Group-Id` 헤더가 모두 없으면 개인 카테고리)
+#[derive(Debug, Deserialize)]
+pub struct CategoryDefaultsRequest {
+    pub group_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CategoryDefaultsResponse {
+    /// 범위에 적용된 기본 카테고리 세트 버전
+    pub version: u32,
+    /// 서버의 최신 기본 카테고리 세트 버전
+    pub latest_version: u32,
+    pub created: u64,
+    pub linked: u64,
+    pub restored: u64,
+    /// 적용 후 범위의 카테고리 트리
+    pub categories: Vec<CategoryNodeResponse>,
+}
+
+impl CategoryDefaultsResponse {
+    pub fn new(result: DefaultCategoryResult, tree: Vec<CategoryNode>) -> Self {
+        Self {
+            version: result.version,
+            latest_version: DEFAULT_CATEGORY_VERSION,
+            created: result.created,
+            linked: result.linked,
+            restored: result.restored,
+            categories: tree.into_iter().map(CategoryNodeResponse::from).collect(),
+        }
+    }
+}
+
+/// `#RRGGBB` 형식의 색상
+fn validate_color(color: &str) -> Result<(), ValidationError> {
+    let valid = color.len() == 7
+        && color.starts_with('#')
+        && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+    if !valid {
+        return Err(ValidationError::new("color"));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use crate::i18n::MessageKey;
+use crate::utils::request_context::current_locale;
+
+#[derive(Debug, Serialize)]
+pub struct MessageResponse {
+    pub message: String,
+}
+
+impl MessageResponse {
+    /// 현재 요청 언어로 번역한 메시지
+    pub fn new(key: MessageKey) -> Self {
+        Self {
+            message: key.text(current_locale()).to_string(),
+        }
+    }
+}
+
+/// `1,2,3` 형태의 쿼리 값을 ID 목록으로 변환합니다. 값이 없으면 빈 목록입니다.
+pub fn deserialize_id_list<'de, D>(deserializer: D) -> Result<Vec<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+
+    value
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| id.parse().map_err(serde::de::Error::custom))
+        .collect()
+}
@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use crate::domain::models::{Group, GroupInvite, GroupMember, GroupRole, User};
+
+#[derive(Debug, Serialize)]
+pub struct GroupResponse {
+    pub id: i64,
+    pub name: String,
+    pub owner_id: i64,
+}
+
+impl From<Group> for GroupResponse {
+    fn from(group: Group) -> Self {
+        Self {
+            id: group.id,
+            name: group.name,
+            owner_id: group.owner_id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct GroupDetailResponse {
+    pub id: i64,
+    pub name: String,
+    pub owner_id: i64,
+    pub member_count: i64,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl GroupDetailResponse {
+    pub fn new(group: Group, member_count: i64) -> Self {
+        Self {
+            id: group.id,
+            name: group.name,
+            owner_id: group.owner_id,
+            member_count,
+            created_at: group.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct GroupCreateRequest {
+    #[validate(length(min = 1, max = 120))]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct GroupUpdateRequest {
+    #[validate(length(min = 1, max = 120))]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransferOwnershipRequest {
+    pub new_owner_id: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GroupMemberResponse {
+    pub user_id: i64,
+    pub nickname: String,
+    pub avatar_url: Option<String>,
+    pub role: GroupRole,
+    pub joined_at: chrono::NaiveDateTime,
+}
+
+impl GroupMemberResponse {
+    pub fn new(member: GroupMember, user: User) -> Self {
+        Self {
+            user_id: member.user_id,
+            nickname: user.nickname,
+            avatar_url: user.avatar_url,
+            role: member.role,
+            joined_at: member.joined_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateMemberRoleRequest {
+    pub role: GroupRole,
+}
+
+/// 초대 코드 생성 요청 (본문 생략 시 24시간, 무제한)
+#[derive(Debug, Default, Deserialize, Validate)]
+pub struct CreateInviteRequest {
+    /// 유효 시간 (최대 7일)
+    #[validate(range(min = 1, max = 168))]
+    pub expires_in_hours: Option<i64>,
+
+    #[validate(range(min = 1, max = 100))]
+    pub max_uses: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct JoinGroupRequest {
+    /// 회원가입 요청과 같은 이름(`invite_code`)도 허용
+    #[serde(alias = "invite_code")]
+    #[validate(length(min = 1, max = 10))]
+    pub code: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InviteResponse {
+    pub id: i64,
+    pub code: String,
+    pub created_by: i64,
+    pub max_uses: Option<i32>,
+    pub use_count: i32,
+    pub expires_at: chrono::NaiveDateTime,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl From<GroupInvite> for InviteResponse {
+    fn from(invite: GroupInvite) -> Self {
+        Self {
+            id: invite.id,
+            code: invite.code,
+            created_by: invite.created_by,
+            max_uses: invite.max_uses,
+            use_count: invite.use_count,
+            expires_at: invite.expires_at,
+            created_at: invite.created_at,
+        }
+    }
+}
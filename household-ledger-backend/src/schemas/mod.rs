@@ -1,3 +1,13 @@
 pub mod auth;
+pub mod category;
+pub mod common;
+pub mod group;
+pub mod tag;
+pub mod transaction;
 
 pub use auth::*;
+pub use category::*;
+pub use common::*;
+pub use group::*;
+pub use tag::*;
+pub use transaction::*;
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use crate::domain::models::Tag;
+
+#[derive(Debug, Serialize)]
+pub struct TagResponse {
+    pub id: i64,
+    pub group_id: Option<i64>,
+    pub created_by: i64,
+    pub name: String,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<Tag> for TagResponse {
+    fn from(tag: Tag) -> Self {
+        Self {
+            id: tag.id,
+            group_id: tag.group_id,
+            created_by: tag.created_by,
+            name: tag.name,
+            created_at: tag.created_at,
+            updated_at: tag.updated_at,
+        }
+    }
+}
+
+/// 태그 목록 조회 (`group_id`와 `X-Group-Id` 헤더가 모두 없으면 개인 태그)
+#[derive(Debug, Deserialize)]
+pub struct TagListQuery {
+    pub group_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct TagCreateRequest {
+    /// 생략하면 개인 태그
+    pub group_id: Option<i64>,
+    #[validate(length(min = 1, max = 60))]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct TagUpdateRequest {
+    #[validate(length(min = 1, max = 60))]
+    pub name: String,
+}
+
+/// 이 태그가 붙은 거래를 모두 `target_tag_id`로 옮기고 이 태그를 삭제
+#[derive(Debug, Deserialize)]
+pub struct TagMergeRequest {
+    pub target_tag_id: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagMergeResponse {
+    pub tag: TagResponse,
+    /// 병합 대상 태그로 옮긴 거래 수
+    pub moved_transactions: u64,
+}
@@ -1,37 +1,460 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
-use validator::Validate;
-use crate::domain::models::TransactionType;
+use serde_json::Value;
+use validator::{Validate, ValidationError, ValidationErrors};
+use crate::application::services::BulkItemOutcome;
+use crate::domain::models::{
+    BulkMode, CategoryTotal, SortDirection, Transaction, TransactionDetails, TransactionFilter,
+    TransactionLinks, TransactionPatch, TransactionSearchHit, TransactionSort,
+    TransactionSortField, TransactionSplit, TransactionType,
+};
+use crate::errors::{AppError, ErrorCode};
+use crate::utils::request_context::current_locale;
+use crate::schemas::category::CategoryResponse;
+use crate::schemas::common::deserialize_id_list;
+use crate::schemas::tag::TagResponse;
+use crate::utils::search::{highlight_snippet, Snippet};
+
+/// 분할 항목 (금액의 합은 거래 금액과 같아야 함)
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct TransactionSplitRequest {
+    pub category_id: Option<i64>,
+    #[validate(range(min = 1))]
+    pub amount: i64,
+    #[validate(length(max = 1000))]
+    pub memo: Option<String>,
+}
+
+impl TransactionSplitRequest {
+    pub fn into_split(self) -> TransactionSplit {
+        let now = chrono::Utc::now().naive_utc();
+        TransactionSplit {
+            id: 0,
+            transaction_id: 0,
+            category_id: self.category_id,
+            amount: self.amount,
+            memo: self.memo,
+            position: 0,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+fn into_splits(splits: Vec<TransactionSplitRequest>) -> Vec<TransactionSplit> {
+    splits.into_iter().map(TransactionSplitRequest::into_split).collect()
+}
 
 #[derive(Debug, Deserialize, Validate)]
 pub struct TransactionCreateRequest {
     pub group_id: Option<i64>,
     #[validate(range(min = 1))]
     pub amount: i64,
+    #[validate(length(equal = 3))]
     pub currency_code: Option<String>,
     pub original_amount: Option<i64>,
     pub category_id: Option<i64>,
-    pub tag_id: Option<i64>,
+    /// 거래와 같은 그룹(개인 거래는 본인)의 태그
+    #[serde(default)]
+    #[validate(length(max = 20))]
+    pub tag_ids: Vec<i64>,
+    #[validate(length(max = 160))]
+    pub merchant: Option<String>,
+    #[validate(length(max = 1000))]
+    pub memo: Option<String>,
+    #[serde(rename = "type")]
+    pub transaction_type: TransactionType,
+    pub date: chrono::NaiveDate,
+    /// 분할 항목 (생략하면 분할하지 않음)
+    #[serde(default)]
+    #[validate(length(max = 50), nested)]
+    pub splits: Vec<TransactionSplitRequest>,
+}
+
+impl TransactionCreateRequest {
+    /// `owner_user_id`가 작성한 새 거래(반복 규칙, 영수증 연결 없음)와 분할 항목, 태그
+    pub fn into_parts(self, owner_user_id: i64) -> (Transaction, TransactionLinks) {
+        let now = chrono::Utc::now().naive_utc();
+        let transaction = Transaction {
+            id: 0,
+            group_id: self.group_id,
+            owner_user_id,
+            transaction_type: self.transaction_type,
+            date: self.date,
+            amount: self.amount,
+            currency_code: self.currency_code,
+            original_amount: self.original_amount,
+            category_id: self.category_id,
+            recurring_rule_id: None,
+            receipt_id: None,
+            merchant: self.merchant,
+            memo: self.memo,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let links = TransactionLinks {
+            splits: Some(into_splits(self.splits)),
+            tag_ids: Some(self.tag_ids),
+        };
+
+        (transaction, links)
+    }
+}
+
+/// 카테고리 이름으로 거래 간편 입력 (같은 이름의 카테고리가 없으면 새로 만듦)
+#[derive(Debug, Deserialize, Validate)]
+pub struct QuickAddTransactionRequest {
+    pub group_id: Option<i64>,
+    #[validate(length(min = 1, max = 120))]
+    pub category_name: String,
+    #[validate(range(min = 1))]
+    pub amount: i64,
+    #[validate(length(equal = 3))]
+    pub currency_code: Option<String>,
+    pub original_amount: Option<i64>,
+    #[serde(default)]
+    #[validate(length(max = 20))]
+    pub tag_ids: Vec<i64>,
+    #[validate(length(max = 160))]
     pub merchant: Option<String>,
     #[validate(length(max = 1000))]
     pub memo: Option<String>,
+    #[serde(rename = "type")]
     pub transaction_type: TransactionType,
     pub date: chrono::NaiveDate,
 }
 
+/// 거래 수정 요청 (전달된 필드만 변경)
+#[derive(Debug, Deserialize, Validate)]
+pub struct TransactionUpdateRequest {
+    pub group_id: Option<i64>,
+    #[validate(range(min = 1))]
+    pub amount: Option<i64>,
+    #[validate(length(equal = 3))]
+    pub currency_code: Option<String>,
+    pub original_amount: Option<i64>,
+    pub category_id: Option<i64>,
+    /// 태그 전체 교체 (빈 목록이면 태그 해제, 생략하면 유지)
+    #[validate(length(max = 20))]
+    pub tag_ids: Option<Vec<i64>>,
+    #[validate(length(max = 160))]
+    pub merchant: Option<String>,
+    #[validate(length(max = 1000))]
+    pub memo: Option<String>,
+    #[serde(rename = "type")]
+    pub transaction_type: Option<TransactionType>,
+    pub date: Option<chrono::NaiveDate>,
+    /// 분할 항목 전체 교체 (빈 목록이면 분할 해제, 생략하면 유지)
+    #[validate(length(max = 50), nested)]
+    pub splits: Option<Vec<TransactionSplitRequest>>,
+}
+
+impl From<TransactionUpdateRequest> for TransactionPatch {
+    fn from(request: TransactionUpdateRequest) -> Self {
+        Self {
+            group_id: request.group_id,
+            amount: request.amount,
+            currency_code: request.currency_code,
+            original_amount: request.original_amount,
+            category_id: request.category_id,
+            merchant: request.merchant,
+            memo: request.memo,
+            transaction_type: request.transaction_type,
+            date: request.date,
+            splits: request.splits.map(into_splits),
+            tag_ids: request.tag_ids,
+        }
+    }
+}
+
+/// 거래 일괄 생성 (한 번에 최대 100건)
+#[derive(Debug, Deserialize)]
+pub struct BulkCreateTransactionsRequest {
+    #[serde(default)]
+    pub mode: BulkMode,
+    pub items: Vec<TransactionCreateRequest>,
+}
+
+impl Validate for BulkCreateTransactionsRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        validate_bulk_items(self.items.len())
+    }
+}
+
+/// 거래 일괄 수정 (항목마다 전달된 필드만 변경)
+#[derive(Debug, Deserialize)]
+pub struct BulkPatchTransactionsRequest {
+    #[serde(default)]
+    pub mode: BulkMode,
+    pub items: Vec<BulkPatchItem>,
+}
+
+impl Validate for BulkPatchTransactionsRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        validate_bulk_items(self.items.len())
+    }
+}
+
+/// `items` 개수 검증 (1~100건)
+///
+/// `#[validate(length)]`는 실패 시 값 전체를 직렬화해 오류에 담으므로
+/// 요청 본문 타입에 `Serialize`가 필요 없도록 개수만 직접 확인합니다.
+fn validate_bulk_items(len: usize) -> Result<(), ValidationErrors> {
+    if (1..=100).contains(&len) {
+        return Ok(());
+    }
+
+    let mut error = ValidationError::new("length");
+    error.add_param("min".into(), &1);
+    error.add_param("max".into(), &100);
+    let mut errors = ValidationErrors::new();
+    errors.add("items", error);
+    Err(errors)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BulkPatchItem {
+    pub id: i64,
+    #[serde(flatten)]
+    pub changes: TransactionUpdateRequest,
+}
+
+/// 거래 일괄 삭제
+#[derive(Debug, Deserialize, Validate)]
+pub struct BulkDeleteTransactionsRequest {
+    #[serde(default)]
+    pub mode: BulkMode,
+    #[validate(length(min = 1, max = 100))]
+    pub ids: Vec<i64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkItemStatus {
+    Succeeded,
+    Failed,
+    /// 다른 항목이 실패해 반영하지 않음 (`all_or_nothing`)
+    Skipped,
+}
+
+/// 항목별 오류 (에러 응답과 같은 `code`, `message`, `details`)
+#[derive(Debug, Serialize)]
+pub struct BulkItemError {
+    pub code: ErrorCode,
+    pub message: String,
+    #[serde(skip_serializing_if = "Value::is_null")]
+    pub details: Value,
+}
+
+impl From<AppError> for BulkItemError {
+    fn from(error: AppError) -> Self {
+        Self {
+            code: error.code(),
+            message: error.message_key().text(current_locale()).to_string(),
+            details: error.details(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkItemResult {
+    /// 요청 목록에서의 위치 (0부터)
+    pub index: usize,
+    pub status: BulkItemStatus,
+    /// 반영된 거래 (삭제는 삭제 전 거래)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<TransactionResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<BulkItemError>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkTransactionResponse {
+    pub mode: BulkMode,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub results: Vec<BulkItemResult>,
+}
+
+impl BulkTransactionResponse {
+    pub fn new(mode: BulkMode, outcomes: Vec<BulkItemOutcome>) -> Self {
+        let results: Vec<BulkItemResult> = outcomes
+            .into_iter()
+            .enumerate()
+            .map(|(index, outcome)| match outcome {
+                BulkItemOutcome::Succeeded(transaction) => BulkItemResult {
+                    index,
+                    status: BulkItemStatus::Succeeded,
+                    transaction: Some(TransactionResponse::from(transaction)),
+                    error: None,
+                },
+                BulkItemOutcome::Failed(error) => BulkItemResult {
+                    index,
+                    status: BulkItemStatus::Failed,
+                    transaction: None,
+                    error: Some(BulkItemError::from(error)),
+                },
+                BulkItemOutcome::Skipped => BulkItemResult {
+                    index,
+                    status: BulkItemStatus::Skipped,
+                    transaction: None,
+                    error: None,
+                },
+            })
+            .collect();
+
+        let count = |status: fn(&BulkItemStatus) -> bool| {
+            results.iter().filter(|result| status(&result.status)).count()
+        };
+
+        Self {
+            mode,
+            succeeded: count(|status| matches!(status, BulkItemStatus::Succeeded)),
+            failed: count(|status| matches!(status, BulkItemStatus::Failed)),
+            skipped: count(|status| matches!(status, BulkItemStatus::Skipped)),
+            results,
+        }
+    }
+}
+
+/// 거래 목록 조회 조건
+///
+/// 여러 값을 받는 조건(`category_ids`, `tag_ids`)은 `1,2,3`처럼 쉼표로 구분합니다.
+#[derive(Debug, Deserialize, Validate)]
+pub struct TransactionListQuery {
+    pub group_id: Option<i64>,
+    pub start_date: Option<chrono::NaiveDate>,
+    pub end_date: Option<chrono::NaiveDate>,
+    #[serde(rename = "type")]
+    pub transaction_type: Option<TransactionType>,
+    #[validate(range(min = 0))]
+    pub min_amount: Option<i64>,
+    #[validate(range(min = 0))]
+    pub max_amount: Option<i64>,
+    /// 단일 카테고리 (`category_ids`와 함께 쓰면 합쳐서 조회)
+    pub category_id: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_id_list")]
+    #[validate(length(max = 50))]
+    pub category_ids: Vec<i64>,
+    #[serde(default, deserialize_with = "deserialize_id_list")]
+    #[validate(length(max = 50))]
+    pub tag_ids: Vec<i64>,
+    #[validate(length(min = 1, max = 160))]
+    pub merchant: Option<String>,
+    /// 작성한 그룹 멤버
+    pub owner_user_id: Option<i64>,
+    #[validate(length(equal = 3))]
+    pub currency_code: Option<String>,
+    pub has_receipt: Option<bool>,
+    pub from_recurring: Option<bool>,
+    #[validate(length(min = 1, max = 100))]
+    pub search: Option<String>,
+    #[serde(default)]
+    pub sort: TransactionSortField,
+    #[serde(default)]
+    pub order: SortDirection,
+    /// 이전 응답의 `next_cursor` (첫 페이지는 생략)
+    #[validate(length(max = 200))]
+    pub cursor: Option<String>,
+    #[serde(default = "default_limit")]
+    #[validate(range(min = 1, max = 100))]
+    pub limit: u32,
+    /// 전체 건수 포함 여부 (COUNT 쿼리가 추가로 실행됨)
+    #[serde(default)]
+    pub include_total: bool,
+}
+
+impl TransactionListQuery {
+    pub fn filter(&self) -> TransactionFilter {
+        let mut category_ids = self.category_ids.clone();
+        if let Some(category_id) = self.category_id {
+            if !category_ids.contains(&category_id) {
+                category_ids.push(category_id);
+            }
+        }
+
+        TransactionFilter {
+            start_date: self.start_date,
+            end_date: self.end_date,
+            transaction_type: self.transaction_type,
+            min_amount: self.min_amount,
+            max_amount: self.max_amount,
+            category_ids,
+            tag_ids: self.tag_ids.clone(),
+            merchant: self.merchant.clone(),
+            owner_user_id: self.owner_user_id,
+            currency_code: self.currency_code.clone(),
+            has_receipt: self.has_receipt,
+            from_recurring: self.from_recurring,
+            search: self.search.clone(),
+        }
+    }
+
+    pub fn sort(&self) -> TransactionSort {
+        TransactionSort {
+            field: self.sort,
+            direction: self.order,
+        }
+    }
+}
+
+fn default_limit() -> u32 {
+    50
+}
+
 #[derive(Debug, Serialize)]
 pub struct TransactionResponse {
     pub id: i64,
     pub group_id: Option<i64>,
     pub owner_user_id: i64,
+    #[serde(rename = "type")]
     pub transaction_type: TransactionType,
     pub date: chrono::NaiveDate,
     pub amount: i64,
     pub currency_code: Option<String>,
     pub original_amount: Option<i64>,
     pub category_id: Option<i64>,
-    pub tag_id: Option<i64>,
+    pub recurring_rule_id: Option<i64>,
+    pub receipt_id: Option<i64>,
     pub merchant: Option<String>,
     pub memo: Option<String>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+    /// 분할 항목 (조회하지 않은 응답에서는 생략, 분할하지 않은 거래는 빈 목록)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splits: Option<Vec<TransactionSplitResponse>>,
+    /// 태그 (조회하지 않은 응답에서는 생략)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<TagResponse>>,
+}
+
+impl TransactionResponse {
+    pub fn with_details(mut self, details: TransactionDetails) -> Self {
+        self.splits = Some(details.splits.into_iter().map(TransactionSplitResponse::from).collect());
+        self.tags = Some(details.tags.into_iter().map(TagResponse::from).collect());
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransactionSplitResponse {
+    pub id: i64,
+    pub category_id: Option<i64>,
+    pub amount: i64,
+    pub memo: Option<String>,
+}
+
+impl From<TransactionSplit> for TransactionSplitResponse {
+    fn from(split: TransactionSplit) -> Self {
+        Self {
+            id: split.id,
+            category_id: split.category_id,
+            amount: split.amount,
+            memo: split.memo,
+        }
+    }
 }
 
 impl From<crate::domain::models::Transaction> for TransactionResponse {
@@ -46,10 +469,193 @@ impl From<crate::domain::models::Transaction> for TransactionResponse {
             currency_code: tx.currency_code,
             original_amount: tx.original_amount,
             category_id: tx.category_id,
-            tag_id: tx.tag_id,
+            recurring_rule_id: tx.recurring_rule_id,
+            receipt_id: tx.receipt_id,
             merchant: tx.merchant,
             memo: tx.memo,
+            created_at: tx.created_at,
+            updated_at: tx.updated_at,
+            splits: None,
+            tags: None,
         }
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct TransactionListResponse {
+    pub items: Vec<TransactionResponse>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+    pub limit: u32,
+}
+
+impl TransactionListResponse {
+    /// `details`는 페이지 거래들의 분할 항목과 태그 (거래 ID별)
+    pub fn new(
+        page: crate::domain::models::TransactionPage,
+        limit: u32,
+        mut details: HashMap<i64, TransactionDetails>,
+    ) -> Self {
+        Self {
+            items: page
+                .items
+                .into_iter()
+                .map(|tx| {
+                    let tx_details = details.remove(&tx.id).unwrap_or_default();
+                    TransactionResponse::from(tx).with_details(tx_details)
+                })
+                .collect(),
+            next_cursor: page.next_cursor.map(|cursor| cursor.encode()),
+            has_more: page.has_more,
+            total: page.total,
+            limit,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuickAddTransactionResponse {
+    pub transaction: TransactionResponse,
+    pub category: CategoryResponse,
+    pub category_created: bool,
+}
+
+impl From<crate::domain::models::QuickAddedTransaction> for QuickAddTransactionResponse {
+    fn from(added: crate::domain::models::QuickAddedTransaction) -> Self {
+        Self {
+            transaction: TransactionResponse::from(added.transaction),
+            category: CategoryResponse::from(added.category),
+            category_created: added.category_created,
+        }
+    }
+}
+
+/// 거래 검색 (입력 중 자동완성)
+#[derive(Debug, Deserialize, Validate)]
+pub struct TransactionSearchQuery {
+    #[validate(length(min = 1, max = 100))]
+    pub q: String,
+    pub group_id: Option<i64>,
+    #[serde(default = "default_search_limit")]
+    #[validate(range(min = 1, max = 50))]
+    pub limit: u32,
+}
+
+fn default_search_limit() -> u32 {
+    20
+}
+
+/// 검색어가 포함된 필드와 강조 구간
+#[derive(Debug, Serialize)]
+pub struct SearchSnippetResponse {
+    /// `merchant`, `memo`, `receipt`
+    pub field: &'static str,
+    #[serde(flatten)]
+    pub snippet: Snippet,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransactionSearchResult {
+    pub transaction: TransactionResponse,
+    pub score: f64,
+    pub snippets: Vec<SearchSnippetResponse>,
+}
+
+/// 스니펫 최대 길이 (글자)
+const SNIPPET_MAX_CHARS: usize = 60;
+
+impl TransactionSearchResult {
+    pub fn new(hit: TransactionSearchHit, terms: &[String]) -> Self {
+        let fields = [
+            ("merchant", hit.transaction.merchant.as_deref()),
+            ("memo", hit.transaction.memo.as_deref()),
+            ("receipt", hit.receipt_text.as_deref()),
+        ];
+        let snippets = fields
+            .into_iter()
+            .filter_map(|(field, text)| {
+                let snippet = highlight_snippet(text?, terms, SNIPPET_MAX_CHARS)?;
+                Some(SearchSnippetResponse { field, snippet })
+            })
+            .collect();
+
+        Self {
+            transaction: TransactionResponse::from(hit.transaction),
+            score: hit.score,
+            snippets,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransactionSearchResponse {
+    pub items: Vec<TransactionSearchResult>,
+}
+
+/// 카테고리별 통계 조회 조건
+#[derive(Debug, Deserialize, Validate)]
+pub struct CategoryStatisticsQuery {
+    pub group_id: Option<i64>,
+    pub start_date: Option<chrono::NaiveDate>,
+    pub end_date: Option<chrono::NaiveDate>,
+    #[serde(rename = "type")]
+    pub transaction_type: Option<TransactionType>,
+    /// 작성한 그룹 멤버
+    pub owner_user_id: Option<i64>,
+    /// 태그 중 하나라도 붙은 거래만 집계 (`1,2,3`)
+    #[serde(default, deserialize_with = "deserialize_id_list")]
+    #[validate(length(max = 50))]
+    pub tag_ids: Vec<i64>,
+    /// 하위 카테고리를 상위 카테고리에 합산
+    #[serde(default)]
+    pub rollup: bool,
+}
+
+impl CategoryStatisticsQuery {
+    pub fn filter(&self) -> TransactionFilter {
+        TransactionFilter {
+            start_date: self.start_date,
+            end_date: self.end_date,
+            transaction_type: self.transaction_type,
+            owner_user_id: self.owner_user_id,
+            tag_ids: self.tag_ids.clone(),
+            ..TransactionFilter::default()
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CategoryTotalResponse {
+    pub category_id: Option<i64>,
+    pub category_name: Option<String>,
+    pub parent_id: Option<i64>,
+    #[serde(rename = "type")]
+    pub transaction_type: TransactionType,
+    pub total_amount: i64,
+    pub transaction_count: i64,
+    pub budget_amount: Option<i64>,
+    /// 예산에서 합계를 뺀 금액 (초과하면 음수)
+    pub remaining_budget: Option<i64>,
+}
+
+impl From<CategoryTotal> for CategoryTotalResponse {
+    fn from(total: CategoryTotal) -> Self {
+        Self {
+            category_id: total.category_id,
+            category_name: total.category_name,
+            parent_id: total.parent_id,
+            transaction_type: total.transaction_type,
+            total_amount: total.total_amount,
+            transaction_count: total.transaction_count,
+            budget_amount: total.budget_amount,
+            remaining_budget: total.budget_amount.map(|budget| budget - total.total_amount),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CategoryStatisticsResponse {
+    pub items: Vec<CategoryTotalResponse>,
+}
@@ -1,2 +1,4 @@
 // 유틸리티 함수는 필요시 추가
 
+pub mod request_context;
+pub mod search;
@@ -0,0 +1,37 @@
+use std::future::Future;
+use crate::i18n::Locale;
+
+tokio::task_local! {
+    static REQUEST_CONTEXT: RequestContext;
+}
+
+/// 요청마다 공유하는 값 (요청 ID, 응답 언어)
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+    pub request_id: String,
+    /// `Accept-Language` 또는 사용자 설정에서 고른 언어 (둘 다 없으면 `None`)
+    pub locale: Option<Locale>,
+}
+
+/// 요청 컨텍스트를 설정한 채로 future를 실행합니다.
+pub async fn scope<F: Future>(context: RequestContext, f: F) -> F::Output {
+    REQUEST_CONTEXT.scope(context, f).await
+}
+
+/// 현재 처리 중인 요청의 컨텍스트 (요청 밖에서 호출하면 `None`)
+pub fn current() -> Option<RequestContext> {
+    REQUEST_CONTEXT.try_with(|context| context.clone()).ok()
+}
+
+pub fn current_request_id() -> Option<String> {
+    REQUEST_CONTEXT.try_with(|context| context.request_id.clone()).ok()
+}
+
+/// 현재 요청의 언어 (지정되지 않았으면 기본 언어)
+pub fn current_locale() -> Locale {
+    REQUEST_CONTEXT
+        .try_with(|context| context.locale)
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
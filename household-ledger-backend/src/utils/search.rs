@@ -0,0 +1,169 @@
+use serde::Serialize;
+
+/// 전문 검색 연산자로 해석되는 문자 (검색어에서 제거)
+const OPERATOR_CHARS: &[char] = &['+', '-', '<', '>', '(', ')', '~', '*', '"', '@'];
+
+/// 검색어를 공백 기준 단어로 나눕니다. 연산자 문자는 지우고 소문자로 바꿉니다.
+pub fn search_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+
+    for word in query.split_whitespace() {
+        let term: String = word
+            .chars()
+            .filter(|c| !OPERATOR_CHARS.contains(c))
+            .flat_map(char::to_lowercase)
+            .collect();
+
+        if !term.is_empty() && !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+
+    terms
+}
+
+/// 검색어가 강조된 본문 일부
+#[derive(Debug, Clone, Serialize)]
+pub struct Snippet {
+    pub text: String,
+    /// `text` 안에서 검색어와 일치하는 구간 `[시작, 끝)` (문자 단위)
+    pub highlights: Vec<(usize, usize)>,
+}
+
+/// `text`에서 처음 일치하는 검색어 주변을 최대 `max_chars`글자로 잘라 강조 구간과 함께 반환합니다.
+///
+/// 일치하는 검색어가 없으면 `None`입니다. 잘린 앞뒤에는 `…`를 붙입니다.
+pub fn highlight_snippet(text: &str, terms: &[String], max_chars: usize) -> Option<Snippet> {
+    let chars: Vec<char> = text.chars().collect();
+    // 글자 수가 바뀌지 않도록 한 글자씩 소문자로 비교
+    let lowered: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    let mut matches: Vec<(usize, usize)> = Vec::new();
+    for term in terms {
+        let term: Vec<char> = term.chars().collect();
+        if term.is_empty() || term.len() > lowered.len() {
+            continue;
+        }
+        for start in 0..=lowered.len() - term.len() {
+            if lowered[start..start + term.len()] == term[..] {
+                matches.push((start, start + term.len()));
+            }
+        }
+    }
+
+    if matches.is_empty() {
+        return None;
+    }
+
+    // 겹치거나 맞닿은 구간은 하나로 합침
+    matches.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(matches.len());
+    for (start, end) in matches {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    // 첫 일치 구간 앞쪽 문맥을 조금 남기고 자름
+    let context = max_chars / 4;
+    let window_start = merged[0].0.saturating_sub(context);
+    let window_end = (window_start + max_chars).min(chars.len());
+
+    let prefix = if window_start > 0 { "…" } else { "" };
+    let suffix = if window_end < chars.len() { "…" } else { "" };
+    let offset = prefix.chars().count();
+
+    let highlights = merged
+        .into_iter()
+        .filter(|(start, end)| *end > window_start && *start < window_end)
+        .map(|(start, end)| {
+            (
+                start.max(window_start) - window_start + offset,
+                end.min(window_end) - window_start + offset,
+            )
+        })
+        .collect();
+
+    Some(Snippet {
+        text: format!(
+            "{}{}{}",
+            prefix,
+            chars[window_start..window_end].iter().collect::<String>(),
+            suffix
+        ),
+        highlights,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn search_terms_strips_operators_and_duplicates() {
+        assert_eq!(
+            search_terms("  Coffee  +STAR*bucks coffee \"\" -"),
+            terms(&["coffee", "starbucks"]),
+        );
+        assert_eq!(search_terms("스타벅스 커피"), terms(&["스타벅스", "커피"]));
+        assert!(search_terms("+-<>()~*\"@").is_empty());
+        assert!(search_terms("   ").is_empty());
+    }
+
+    #[test]
+    fn highlight_returns_none_without_match() {
+        assert!(highlight_snippet("Starbucks", &terms(&["coffee"]), 50).is_none());
+        assert!(highlight_snippet("Starbucks", &[], 50).is_none());
+        assert!(highlight_snippet("", &terms(&["a"]), 50).is_none());
+    }
+
+    #[test]
+    fn highlight_counts_characters_not_bytes() {
+        let snippet = highlight_snippet("Starbucks 강남점", &terms(&["강남"]), 50).unwrap();
+
+        assert_eq!(snippet.text, "Starbucks 강남점");
+        assert_eq!(snippet.highlights, vec![(10, 12)]);
+    }
+
+    #[test]
+    fn highlight_ignores_case() {
+        let snippet = highlight_snippet("Coffee and COFFEE", &terms(&["coffee"]), 100).unwrap();
+
+        assert_eq!(snippet.highlights, vec![(0, 6), (11, 17)]);
+    }
+
+    #[test]
+    fn highlight_merges_overlapping_and_adjacent_matches() {
+        let overlapping = highlight_snippet("abcdef", &terms(&["abc", "cde"]), 10).unwrap();
+        let adjacent = highlight_snippet("abcdef", &terms(&["ab", "cd"]), 10).unwrap();
+
+        assert_eq!(overlapping.highlights, vec![(0, 5)]);
+        assert_eq!(adjacent.highlights, vec![(0, 4)]);
+    }
+
+    #[test]
+    fn highlight_trims_around_first_match() {
+        let text = format!("{}target{}", "x".repeat(30), "y".repeat(30));
+        let snippet = highlight_snippet(&text, &terms(&["target"]), 20).unwrap();
+
+        assert_eq!(snippet.text, format!("…{}target{}…", "x".repeat(5), "y".repeat(9)));
+        assert_eq!(snippet.highlights, vec![(6, 12)]);
+    }
+
+    #[test]
+    fn highlight_drops_matches_outside_window() {
+        let text = format!("target{}target", "x".repeat(30));
+        let snippet = highlight_snippet(&text, &terms(&["target"]), 10).unwrap();
+
+        assert_eq!(snippet.text, "targetxxxx…");
+        assert_eq!(snippet.highlights, vec![(0, 6)]);
+    }
+}